@@ -6,13 +6,24 @@
 //! In addition to the features of the example iced toast code, this create supports:
 //!
 //! - Optional title, level and action buttons
+//! - Per-toast timeouts, and sticky toasts that never time out
+//! - Custom widgets inside toasts, such as progress bars and small forms
+//! - Loading toasts with an animated spinner
+//! - Progress bar toasts, which can turn into success toasts once complete
+//! - Promise toasts, which follow the result of an iced `Task`
+//! - Streaming toasts, which follow the items of a `Stream`
+//! - Fully custom toast appearance, keeping the queueing, timing and positioning
+//! - Optional countdown bars showing how long toasts have left
+//! - Enter and exit animations with configurable easing and duration
+//! - A compact deck layout, which expands into a full stack on hover
+//! - Swipe to dismiss toasts with the mouse or a finger
 //! - Styling and positioning options
-//! - Toasts will not automatically disappear if being actively hovered over
+//! - Toast timers pause while being actively hovered over
 //!
 //! # Example
 //! Here is a minimal example to push toasts to the screen
 //!
-//! ```rust
+//! ```no_run
 //! use iced::{
 //!     Element,
 //!     widget::{button, text},
@@ -21,7 +32,7 @@
 //! use iced_toasts::{ToastContainer, ToastId, ToastLevel, toast, toast_container};
 //!
 //! pub fn main() -> iced::Result {
-//!     iced::run("Iced Toasts Example", App::update, App::view)
+//!     iced::application(App::default, App::update, App::view).run()
 //! }
 //!
 //! struct App<'a, Message> {
@@ -52,8 +63,8 @@
 //!                         .level(ToastLevel::Success),
 //!                 );
 //!             }
-//!             Message::DismissToast(id) => {
-//!                 self.toasts.dismiss(id);
+//!             Message::DismissToast(_id) => {
+//!                 // The toast is removed automatically once it is dismissed.
 //!             }
 //!         }
 //!     }
@@ -98,6 +109,7 @@
 //! ```rust
 //! use iced_toasts::{toast_container, alignment, ToastId};
 //!
+//! #[derive(Debug, Clone)]
 //! enum Message {
 //!     DismissToast(ToastId),
 //! }
@@ -113,8 +125,12 @@
 //! takes a reference to a theme and returns the [`Style`] struct.
 //!
 //! ```rust
-//! use iced_toasts::{toast_container, alignment, ToastId};
+//! use std::rc::Rc;
 //!
+//! use iced::{Border, Shadow};
+//! use iced_toasts::{toast_container, ToastId};
+//!
+//! #[derive(Debug, Clone)]
 //! enum Message {
 //!     DismissToast(ToastId),
 //! }
@@ -128,32 +144,46 @@
 //!             border: Border::default(),
 //!             shadow: Shadow::default(),
 //!             level_to_color: Rc::new(|_level| None),
+//!             action_to_color: Rc::new(move |_kind| palette.primary.base.color),
 //!         }
 //!     });
 //! ```
 
-use std::{cell::RefCell, cmp, rc::Rc};
+use std::{
+    any::Any,
+    cell::{Cell, RefCell},
+    collections::HashMap,
+    rc::Rc,
+};
 
 use iced::{
     Background, Border, Color, Element, Event, Length, Padding, Pixels, Point, Rectangle, Renderer,
-    Shadow, Size, Theme, Vector,
+    Shadow, Size, Task, Theme, Transformation, Vector,
     advanced::{
         Clipboard, Layout, Shell, Widget,
-        layout::{self, Limits, Node, flex::Axis},
+        layout::{Limits, Node},
         mouse::{self, Cursor, Interaction},
         overlay,
-        renderer::{self},
+        renderer::{self, Renderer as _},
+        text::{self, Paragraph as _, Renderer as _, Text},
         widget::{
             Operation, Tree,
             tree::{State, Tag},
         },
     },
-    time, window,
+    animation::Easing,
+    futures::{Stream, StreamExt, stream},
+    time, touch, window,
 };
 
 mod toast;
+pub use toast::Action as ToastAction;
+pub use toast::ActionKind;
+pub use toast::DismissReason;
+pub use toast::Event as ToastEvent;
 pub use toast::Id as ToastId;
 pub use toast::Level as ToastLevel;
+pub use toast::Toast as ToastData;
 
 pub mod alignment {
     //! This module provides some structs for choosing where toasts will display
@@ -167,9 +197,9 @@ pub mod alignment {
         Right,
     }
 
-    impl Into<iced::alignment::Alignment> for Horizontal {
-        fn into(self) -> iced::alignment::Alignment {
-            match self {
+    impl From<Horizontal> for iced::alignment::Alignment {
+        fn from(horizontal: Horizontal) -> Self {
+            match horizontal {
                 Horizontal::Left => iced::alignment::Horizontal::Left,
                 Horizontal::Center => iced::alignment::Horizontal::Center,
                 Horizontal::Right => iced::alignment::Horizontal::Right,
@@ -185,9 +215,9 @@ pub mod alignment {
         Bottom,
     }
 
-    impl Into<iced::alignment::Alignment> for Vertical {
-        fn into(self) -> iced::alignment::Alignment {
-            match self {
+    impl From<Vertical> for iced::alignment::Alignment {
+        fn from(vertical: Vertical) -> Self {
+            match vertical {
                 Vertical::Top => iced::alignment::Vertical::Top,
                 Vertical::Bottom => iced::alignment::Vertical::Bottom,
             }
@@ -197,14 +227,50 @@ pub mod alignment {
 }
 
 mod toast_builder {
-    use super::ToastLevel;
+    use std::{
+        hash::{DefaultHasher, Hash, Hasher},
+        rc::Rc,
+    };
+
+    use iced::{Element, time};
+
+    use super::{ToastAction, ToastId, ToastLevel};
+    use crate::toast::Content;
 
     #[derive(Default, Clone, Debug)]
-    pub struct ToastBuilder<Message> {
+    pub struct ToastBuilder<'a, Message> {
         pub(crate) message: String,
         pub(crate) title: Option<String>,
         pub(crate) level: Option<ToastLevel>,
-        pub(crate) action: Option<(String, Message)>,
+        pub(crate) actions: Vec<ToastAction<Message>>,
+        pub(crate) timeout: Option<time::Duration>,
+        pub(crate) sticky: bool,
+        pub(crate) key: Option<u64>,
+        pub(crate) content: Option<Content<'a, Message>>,
+        pub(crate) loading: bool,
+        pub(crate) progress: Option<f32>,
+        pub(crate) percentage: bool,
+        pub(crate) success_message: Option<String>,
+    }
+
+    impl<'a, Message: Clone> ToastBuilder<'a, Message> {
+        // Recovers the builder that would produce an existing toast.
+        pub(crate) fn from_toast(toast: &crate::toast::Toast<'a, Message>) -> Self {
+            ToastBuilder {
+                message: toast.message.clone(),
+                title: toast.title.clone(),
+                level: toast.level,
+                actions: toast.actions.clone(),
+                timeout: toast.timeout,
+                sticky: toast.timeout.is_none(),
+                key: toast.key,
+                content: toast.content.clone(),
+                loading: toast.loading,
+                progress: toast.progress,
+                percentage: toast.percentage,
+                success_message: toast.success_message.clone(),
+            }
+        }
     }
 
     /// Starts building a new toast with the provided message. Optional fields can
@@ -223,16 +289,30 @@ mod toast_builder {
     ///     .level(ToastLevel::Success)
     ///     .action("Undo", Message::UndoFileCreation);
     /// ```
-    pub fn toast<Message>(message: &str) -> ToastBuilder<Message> {
+    pub fn toast<'a, Message>(message: &str) -> ToastBuilder<'a, Message> {
         ToastBuilder {
             message: message.to_string(),
             title: None,
             level: None,
-            action: None,
+            actions: Vec::new(),
+            timeout: None,
+            sticky: false,
+            key: None,
+            content: None,
+            loading: false,
+            progress: None,
+            percentage: false,
+            success_message: None,
         }
     }
 
-    impl<Message> ToastBuilder<Message> {
+    impl<'a, Message> ToastBuilder<'a, Message> {
+        /// Replaces the message of `Toast`.
+        pub fn message(mut self, message: &str) -> Self {
+            self.message = message.to_string();
+            self
+        }
+
         /// Adds an optional title to `Toast`.
         ///
         /// If not set, the toast will have no title.
@@ -241,6 +321,15 @@ mod toast_builder {
             self
         }
 
+        /// Removes the title of `Toast`, such as when updating a toast with
+        /// [`ToastContainer::update`].
+        ///
+        /// [`ToastContainer::update`]: crate::ToastContainer::update
+        pub fn clear_title(mut self) -> Self {
+            self.title = None;
+            self
+        }
+
         /// Adds an optional level to `Toast`.
         ///
         /// If not set, the toast will not have a colored border.
@@ -249,18 +338,226 @@ mod toast_builder {
             self
         }
 
-        /// Adds an optional action button to `Toast`.
+        /// Adds an optional action button to `Toast`. This can be called
+        /// multiple times to add several action buttons.
         ///
         /// `text`` is displayed on the button, and `message` is broadcast when
-        /// the action button is pressed.
+        /// the action button is pressed. Pressing the button also dismisses the
+        /// toast, which can be changed by using [`ToastAction`] with
+        /// [`Toast::actions`] instead.
         pub fn action(mut self, text: &str, message: Message) -> Self {
-            self.action = Some((text.to_string(), message));
+            self.actions.push(ToastAction::new(text, message));
+            self
+        }
+
+        /// Adds several action buttons to `Toast` at once, which are displayed
+        /// in a row underneath the message. Use [`ToastAction`] to change the
+        /// kind of an action.
+        ///
+        /// # Example
+        /// ```rust
+        /// use iced_toasts::{toast, ActionKind, ToastAction, ToastLevel};
+        ///
+        /// enum Message {
+        ///     Retry,
+        ///     ShowDetails,
+        ///     Delete,
+        /// }
+        ///
+        /// toast("Failed to upload file")
+        ///     .level(ToastLevel::Error)
+        ///     .actions([
+        ///         ToastAction::new("Retry", Message::Retry),
+        ///         ToastAction::new("Details", Message::ShowDetails).kind(ActionKind::Secondary),
+        ///         ToastAction::new("Delete", Message::Delete).kind(ActionKind::Destructive),
+        ///     ]);
+        /// ```
+        pub fn actions(
+            mut self,
+            actions: impl IntoIterator<Item = impl Into<ToastAction<Message>>>,
+        ) -> Self {
+            self.actions.extend(actions.into_iter().map(Into::into));
+            self
+        }
+
+        /// Removes every action button of `Toast`. Since [`Toast::action`] and
+        /// [`Toast::actions`] add to the existing buttons, call this first to
+        /// replace the buttons when updating a toast with
+        /// [`ToastContainer::update`].
+        ///
+        /// [`ToastContainer::update`]: crate::ToastContainer::update
+        pub fn clear_actions(mut self) -> Self {
+            self.actions.clear();
+            self
+        }
+
+        /// Sets the amount of time `Toast` has before it disappears.
+        ///
+        /// If not set, the timeout of the [`ToastContainer`] is used.
+        ///
+        /// [`ToastContainer`]: crate::ToastContainer
+        pub fn timeout(mut self, timeout: time::Duration) -> Self {
+            self.timeout = Some(timeout);
+            self.sticky = false;
+            self
+        }
+
+        /// Makes `Toast` sticky, so that it never disappears on its own. A
+        /// sticky toast stays on-screen until it is dismissed.
+        pub fn sticky(mut self) -> Self {
+            self.timeout = None;
+            self.sticky = true;
+            self
+        }
+
+        /// Makes `Toast` a loading toast, which displays an animated spinner in
+        /// place of the level accent, and never disappears on its own. This
+        /// suits toasts such as "Connecting..." or "Exporting...".
+        ///
+        /// Once the task the toast describes has finished, update the toast
+        /// with [`ToastContainer::update`] and call [`Toast::finish`] to turn
+        /// it back into a regular toast, which then times out as usual.
+        ///
+        /// # Example
+        /// ```rust
+        /// use iced_toasts::{toast, toast_container, ToastId, ToastLevel};
+        ///
+        /// #[derive(Debug, Clone)]
+        /// enum Message {
+        ///     DismissToast(ToastId),
+        /// }
+        ///
+        /// let mut toasts = toast_container(Message::DismissToast);
+        /// let id = toasts.push(toast("Exporting...").loading());
+        ///
+        /// // Once exporting has finished:
+        /// toasts.update(id, |toast| {
+        ///     toast
+        ///         .message("Export complete")
+        ///         .level(ToastLevel::Success)
+        ///         .finish()
+        /// });
+        /// ```
+        ///
+        /// [`ToastContainer::update`]: crate::ToastContainer::update
+        pub fn loading(mut self) -> Self {
+            self.loading = true;
+            self
+        }
+
+        /// Makes `Toast` a progress toast, which displays a progress bar
+        /// starting at `progress`, from 0.0 to 1.0. Like a loading toast, it
+        /// never disappears on its own while its task is in progress.
+        ///
+        /// The progress can be changed with [`ToastContainer::set_progress`].
+        /// Like a loading toast, it can be turned back into a regular toast
+        /// with [`Toast::finish`].
+        ///
+        /// # Example
+        /// ```rust
+        /// use iced_toasts::{toast, toast_container, ToastId};
+        ///
+        /// #[derive(Debug, Clone)]
+        /// enum Message {
+        ///     DismissToast(ToastId),
+        /// }
+        ///
+        /// let mut toasts = toast_container(Message::DismissToast);
+        /// let id = toasts.push(
+        ///     toast("Copying 3 files...")
+        ///         .progress(0.0)
+        ///         .percentage()
+        ///         .success_on_complete("Copied 3 files"),
+        /// );
+        ///
+        /// // As the files are copied:
+        /// toasts.set_progress(id, 0.5);
+        /// ```
+        ///
+        /// [`ToastContainer::set_progress`]: crate::ToastContainer::set_progress
+        pub fn progress(mut self, progress: f32) -> Self {
+            self.progress = Some(progress.clamp(0.0, 1.0));
+            self
+        }
+
+        /// Displays the progress of `Toast` as a percentage such as "42%"
+        /// beside its progress bar.
+        pub fn percentage(mut self) -> Self {
+            self.percentage = true;
+            self
+        }
+
+        /// Turns `Toast` into a success toast with `message` once its progress
+        /// reaches 1.0. The success toast then times out as usual, after the
+        /// timeout set with [`Toast::timeout`] if there is one.
+        ///
+        /// If not set, the toast stays on-screen with a full progress bar.
+        pub fn success_on_complete(mut self, message: &str) -> Self {
+            self.success_message = Some(message.to_string());
+            self
+        }
+
+        /// Turns a loading or progress `Toast` back into a regular toast,
+        /// which then times out as usual. Its spinner, progress bar and
+        /// success message are removed.
+        pub fn finish(mut self) -> Self {
+            self.loading = false;
+            self.progress = None;
+            self.percentage = false;
+            self.success_message = None;
+            self
+        }
+
+        /// Adds an optional key to `Toast`.
+        ///
+        /// Pushing a toast with the same key as a toast that is already
+        /// on-screen replaces that toast and restarts its timer, instead of
+        /// displaying a duplicate.
+        pub fn key(mut self, key: impl Hash) -> Self {
+            let mut hasher = DefaultHasher::new();
+            key.hash(&mut hasher);
+            self.key = Some(hasher.finish());
+            self
+        }
+
+        /// Displays custom content in `Toast` in place of its message. The
+        /// content is still wrapped in the usual container, with the level
+        /// border, title and dismiss button around it.
+        ///
+        /// `content` receives the id of the toast and can produce any
+        /// element, such as an image or a small form. Messages produced by the
+        /// content are broadcast to the application. The message of the toast
+        /// is no longer displayed, and toasts with custom content are never
+        /// collapsed into one another.
+        ///
+        /// # Example
+        /// ```rust
+        /// use iced::widget::{button, column, progress_bar, text};
+        /// use iced_toasts::{toast, ToastId};
+        ///
+        /// #[derive(Debug, Clone)]
+        /// enum Message {
+        ///     CancelUpload(ToastId),
+        /// }
+        ///
+        /// toast("Uploading photo.png").content(|id| {
+        ///     column![
+        ///         text("Uploading photo.png"),
+        ///         progress_bar(0.0..=1.0, 0.4),
+        ///         button("Cancel").on_press(Message::CancelUpload(id)),
+        ///     ]
+        ///     .spacing(4)
+        ///     .into()
+        /// });
+        /// ```
+        pub fn content(mut self, content: impl Fn(ToastId) -> Element<'a, Message> + 'a) -> Self {
+            self.content = Some(Content(Rc::new(content)));
             self
         }
     }
 }
 
-pub type Toast<Message> = toast_builder::ToastBuilder<Message>;
+pub type Toast<'a, Message> = toast_builder::ToastBuilder<'a, Message>;
 pub use toast_builder::toast;
 
 /// A component responsible for managing the state of toasts. This should be
@@ -269,22 +566,34 @@ pub use toast_builder::toast;
 ///
 /// # Example
 /// ```rust
+/// use iced_toasts::{toast_container, ToastId};
+///
+/// #[derive(Debug, Clone)]
 /// enum Message {
 ///     DismissToast(ToastId),
 /// }
+///
 /// let toasts = toast_container(Message::DismissToast);
 /// ```
 pub struct ToastContainer<'a, Message> {
-    toasts: Rc<RefCell<Vec<toast::Toast<Message>>>>,
+    toasts: Rc<RefCell<Vec<toast::Toast<'a, Message>>>>,
     next_toast_id: ToastId,
     timeout_duration: time::Duration,
-    on_dismiss: Rc<Box<dyn Fn(ToastId) -> Message + 'a>>,
+    dismissed: Rc<RefCell<Vec<(ToastId, DismissReason)>>>,
+    on_dismiss: OnDismiss<'a, Message>,
     alignment_x: alignment::Horizontal,
     alignment_y: alignment::Vertical,
     text_size: Pixels,
     style_fn: StyleFn<'a>,
-    // TODO: Add an option to disable extending the timeout when the mouse
-    // is hovered over the toasts.
+    hover: Hover,
+    max_visible: Option<usize>,
+    collapse_duplicates: bool,
+    countdown: bool,
+    deck: bool,
+    swipe_to_dismiss: bool,
+    animation: Animation,
+    view_toast: Option<ViewToast<'a, Message>>,
+    pending: Vec<(ToastId, Resolve<'a, Message>)>,
 }
 
 /// Creates a new [`ToastContainer`], which is responsible for managing the
@@ -292,11 +601,17 @@ pub struct ToastContainer<'a, Message> {
 /// chaining builder methods, and get the associated [`Element`] using
 /// [`ToastContainer::view()`].
 ///
-/// The message produced by `on_dismiss(ToastId)` is broadcasted whenever the user
-/// clicks the dismiss button on a toast.
+/// The message produced by `on_dismiss(ToastId)` is broadcasted whenever a toast
+/// is dismissed, such as when it times out or the user clicks its dismiss
+/// button. The toast then animates out and is removed automatically, so there
+/// is no need to call [`ToastContainer::dismiss`] in response. To find out why
+/// a toast was dismissed, use [`ToastContainer::on_dismiss`] instead.
 ///
 /// # Example
 /// ```rust
+/// use iced_toasts::{toast_container, ToastId};
+///
+/// #[derive(Debug, Clone)]
 /// enum Message {
 ///     DismissToast(ToastId),
 /// }
@@ -318,14 +633,60 @@ where
             toasts: Rc::new(RefCell::new(Vec::new())),
             next_toast_id: ToastId::new(),
             timeout_duration: time::Duration::new(5, 0),
-            on_dismiss: Rc::new(Box::new(on_dismiss)),
+            dismissed: Rc::new(RefCell::new(Vec::new())),
+            on_dismiss: Rc::new(Box::new(move |id, _reason| on_dismiss(id))),
             alignment_x: alignment::Horizontal::Right,
             alignment_y: alignment::Vertical::Bottom,
             text_size: 16.into(),
             style_fn: StyleFn::default(),
+            hover: Hover {
+                pause: true,
+                grace: time::Duration::new(2, 0),
+                scope: HoverScope::Stack,
+            },
+            max_visible: None,
+            collapse_duplicates: false,
+            countdown: false,
+            deck: false,
+            swipe_to_dismiss: true,
+            animation: Animation {
+                transition: Transition::SlideFade,
+                duration: time::Duration::from_millis(200),
+                easing: Easing::EaseOutCubic,
+                reduced_motion: false,
+            },
+            view_toast: None,
+            pending: Vec::new(),
         }
     }
 
+    /// Sets the message that is broadcast whenever a toast is dismissed,
+    /// replacing the one given to [`toast_container()`]. Unlike the original,
+    /// `on_dismiss` also receives the [`DismissReason`] the toast was
+    /// dismissed for, so that timeouts can be told apart from the user closing
+    /// the toast.
+    ///
+    /// # Example
+    /// ```rust
+    /// use iced_toasts::{toast_container, DismissReason, ToastId};
+    ///
+    /// #[derive(Debug, Clone)]
+    /// enum Message {
+    ///     DismissToast(ToastId),
+    ///     ToastDismissed(ToastId, DismissReason),
+    /// }
+    ///
+    /// let toasts = toast_container(Message::DismissToast)
+    ///     .on_dismiss(Message::ToastDismissed);
+    /// ```
+    pub fn on_dismiss(
+        mut self,
+        on_dismiss: impl Fn(ToastId, DismissReason) -> Message + 'a,
+    ) -> Self {
+        self.on_dismiss = Rc::new(Box::new(on_dismiss));
+        self
+    }
+
     /// Sets the horizontal position at which the toasts will appear.
     pub fn alignment_x(mut self, alignment: alignment::Horizontal) -> Self {
         self.alignment_x = alignment;
@@ -339,12 +700,130 @@ where
     }
 
     /// Sets the amount of time toasts have before they disappear. Default is 5
-    /// seconds.
+    /// seconds. Individual toasts can override this with `Toast::timeout` or
+    /// `Toast::sticky`.
     pub fn timeout(mut self, timeout: time::Duration) -> Self {
         self.timeout_duration = timeout;
         self
     }
 
+    /// Sets whether toast timers pause while the mouse is hovered over the
+    /// toasts. Once the mouse leaves, the timers resume from where they left
+    /// off. Default is `true`.
+    pub fn pause_on_hover(mut self, pause: bool) -> Self {
+        self.hover.pause = pause;
+        self
+    }
+
+    /// Sets the minimum amount of time toasts have left once the mouse stops
+    /// hovering over them, so they do not disappear the moment the mouse
+    /// leaves. Default is 2 seconds.
+    pub fn hover_grace(mut self, grace: time::Duration) -> Self {
+        self.hover.grace = grace;
+        self
+    }
+
+    /// Sets which toasts pause while the mouse is hovered over them. Default is
+    /// [`HoverScope::Stack`].
+    pub fn hover_scope(mut self, scope: HoverScope) -> Self {
+        self.hover.scope = scope;
+        self
+    }
+
+    /// Sets the maximum number of toasts displayed at once. Any extra toasts
+    /// wait in a queue, and are displayed in the order they were pushed as
+    /// other toasts are dismissed. Their timers do not start until they are
+    /// displayed.
+    ///
+    /// Regardless of this setting, toasts are also queued if they cannot fit
+    /// on-screen. While toasts are queued, a summary row such as "+4 more
+    /// notifications" is displayed. If there is room on-screen for more
+    /// toasts, clicking it lifts the maximum. Otherwise, clicking it moves the
+    /// displayed toasts to the back of the queue, so that the next batch of
+    /// toasts is displayed. By default, there is no maximum.
+    ///
+    /// At least one toast is always displayed, so a maximum of 0 is treated as
+    /// 1.
+    pub fn max_visible(mut self, max_visible: usize) -> Self {
+        self.max_visible = Some(max_visible.max(1));
+        self
+    }
+
+    /// Sets whether pushing a toast with the same title, message, level and
+    /// timeout as a toast that is already on-screen collapses them into a
+    /// single toast. The collapsed toast displays a counter such as "×5", and
+    /// restarts its timer each time a duplicate is pushed. Toasts with a key,
+    /// action buttons, custom content, a spinner or a progress bar are never
+    /// collapsed. Default is `false`.
+    pub fn collapse_duplicates(mut self, collapse: bool) -> Self {
+        self.collapse_duplicates = collapse;
+        self
+    }
+
+    /// Sets whether toasts display a thin bar along their bottom edge, which
+    /// shrinks to show how much time they have left before they disappear.
+    /// The bar stops shrinking while the timer of a toast is paused. Toasts
+    /// that never time out do not display the bar. Default is `false`.
+    pub fn countdown(mut self, countdown: bool) -> Self {
+        self.countdown = countdown;
+        self
+    }
+
+    /// Sets whether toasts are displayed as a compact deck while the mouse is
+    /// not over them. The newest toast is displayed in front, with older
+    /// toasts peeking out from behind it. Hovering over the deck expands it
+    /// into the usual stack. Default is `false`.
+    pub fn deck(mut self, deck: bool) -> Self {
+        self.deck = deck;
+        self
+    }
+
+    /// Sets whether toasts can be dismissed by dragging them sideways with the
+    /// mouse or a finger, away from the edge of the screen they are aligned
+    /// to. Centered toasts can be dragged either way. Toasts which are not
+    /// dragged far or fast enough snap back into place.
+    ///
+    /// Dragging only starts when the toast itself is pressed, so widgets
+    /// inside the toast which handle the press, such as buttons and sliders,
+    /// keep working as usual. Default is `true`.
+    pub fn swipe_to_dismiss(mut self, swipe_to_dismiss: bool) -> Self {
+        self.swipe_to_dismiss = swipe_to_dismiss;
+        self
+    }
+
+    /// Sets how toasts are animated as they appear and disappear. Toasts always
+    /// fade when [`ToastContainer::reduced_motion`] is set. Default is
+    /// [`Transition::SlideFade`].
+    pub fn transition(mut self, transition: Transition) -> Self {
+        self.animation.transition = transition;
+        self
+    }
+
+    /// Sets the amount of time toasts take to animate in and out. A duration
+    /// of zero makes toasts appear and disappear instantly. Default is 200
+    /// milliseconds.
+    pub fn animation_duration(mut self, duration: time::Duration) -> Self {
+        self.animation.duration = duration;
+        self
+    }
+
+    /// Sets the easing of the animations of toasts. Default is
+    /// [`Easing::EaseOutCubic`].
+    pub fn easing(mut self, easing: Easing) -> Self {
+        self.animation.easing = easing;
+        self
+    }
+
+    /// Sets whether toasts avoid motion, for users who are sensitive to it.
+    /// Toasts then only fade in and out, the stack rearranges itself
+    /// instantly, countdown bars shrink in steps instead of continuously, and
+    /// loading spinners stand still.
+    /// Default is `false`.
+    pub fn reduced_motion(mut self, reduced_motion: bool) -> Self {
+        self.animation.reduced_motion = reduced_motion;
+        self
+    }
+
     /// Sets the text size of the toast. Default is 16.
     pub fn size(mut self, size: impl Into<Pixels>) -> Self {
         self.text_size = size.into();
@@ -357,27 +836,500 @@ where
         self
     }
 
-    /// Displays a new toast on-screen.
-    pub fn push(&mut self, toast: Toast<Message>) {
+    /// Replaces the appearance of every toast with the element returned by
+    /// `view_toast`, while the [`ToastContainer`] still handles queueing,
+    /// timing and positioning. `view_toast` receives the [`ToastData`] of a
+    /// toast and the [`StyleFn`] set with [`ToastContainer::style`].
+    ///
+    /// The element produces [`ToastEvent`]s, so that it can dismiss the toast
+    /// with [`ToastEvent::Dismiss`], press an action with
+    /// [`ToastEvent::Action`], or broadcast any other message with
+    /// [`ToastEvent::Publish`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use iced::widget::{button, column, container, row, text};
+    /// use iced_toasts::{toast_container, ToastEvent, ToastId};
+    ///
+    /// #[derive(Debug, Clone)]
+    /// enum Message {
+    ///     DismissToast(ToastId),
+    /// }
+    ///
+    /// let toasts = toast_container(Message::DismissToast).view_toast(|toast, style| {
+    ///     let actions = toast.actions.iter().map(|action| {
+    ///         button(text(action.text.clone()))
+    ///             .on_press(ToastEvent::Action {
+    ///                 message: action.message.clone(),
+    ///                 dismiss: action.dismiss,
+    ///             })
+    ///             .into()
+    ///     });
+    ///
+    ///     container(row![
+    ///         column![
+    ///             text(toast.title.clone().unwrap_or_default()),
+    ///             text(toast.message.clone()),
+    ///         ],
+    ///         row(actions),
+    ///         button("Close").on_press(ToastEvent::Dismiss),
+    ///     ])
+    ///     .padding(12)
+    ///     .style(move |theme| {
+    ///         let style = style.style(theme);
+    ///         container::Style {
+    ///             text_color: style.text_color,
+    ///             background: style.background,
+    ///             border: style.border,
+    ///             ..container::Style::default()
+    ///         }
+    ///     })
+    ///     .into()
+    /// });
+    /// ```
+    pub fn view_toast(
+        mut self,
+        view_toast: impl Fn(&ToastData<'a, Message>, StyleFn<'a>) -> Element<'a, ToastEvent<Message>>
+        + 'a,
+    ) -> Self {
+        self.view_toast = Some(Rc::new(view_toast));
+        self
+    }
+
+    /// Displays a new toast on-screen, and returns its [`ToastId`]. The id can
+    /// be used to refer to the toast later, such as to dismiss it once a
+    /// long-running task has finished.
+    ///
+    /// If the toast has a key matching a toast that is already on-screen, the
+    /// new toast replaces it in the stack, keeping its [`ToastId`] and
+    /// restarting its timer. The `on_dismiss` message is then broadcast for
+    /// the replaced toast with [`DismissReason::Replaced`].
+    /// Otherwise, if [`ToastContainer::collapse_duplicates`] is set and the
+    /// toast has the same title, message, level and timeout as a toast that is
+    /// already on-screen, the two are collapsed into one toast with a counter.
+    ///
+    /// # Example
+    /// ```rust
+    /// use iced_toasts::{toast, toast_container, ToastId};
+    ///
+    /// #[derive(Debug, Clone)]
+    /// enum Message {
+    ///     DismissToast(ToastId),
+    /// }
+    ///
+    /// let mut toasts = toast_container(Message::DismissToast);
+    /// let id = toasts.push(toast("Uploading...").sticky());
+    ///
+    /// // Once the upload has finished:
+    /// toasts.dismiss(id);
+    /// ```
+    pub fn push(&mut self, toast: Toast<'a, Message>) -> ToastId {
+        // Identical toasts are folded into the existing toast, which keeps
+        // its id and has its timer restarted.
+        // Toasts with actions are left alone, so that the actions of the
+        // existing toast are not lost.
+        if self.collapse_duplicates
+            && toast.key.is_none()
+            && toast.actions.is_empty()
+            && toast.content.is_none()
+            && !toast.loading
+            && toast.progress.is_none()
+        {
+            let timeout = self.timeout_of(&toast);
+            let mut toasts = self.toasts.borrow_mut();
+            if let Some(existing) = toasts.iter_mut().find(|existing| {
+                existing.exiting.is_none()
+                    && existing.key.is_none()
+                    && existing.actions.is_empty()
+                    && existing.content.is_none()
+                    && !existing.is_in_progress()
+                    && existing.level == toast.level
+                    && existing.title == toast.title
+                    && existing.message == toast.message
+                    && existing.timeout == timeout
+            }) {
+                let count = existing.count + 1;
+                *existing = self.build(existing.id, toast);
+                existing.count = count;
+                return existing.id;
+            }
+        }
+
+        // A toast with a matching key takes the place of the existing toast,
+        // which keeps its id so that it can still be referred to, and does not
+        // animate in again.
+        if toast.key.is_some() {
+            let mut toasts = self.toasts.borrow_mut();
+            if let Some(existing) = toasts
+                .iter_mut()
+                .find(|existing| existing.exiting.is_none() && existing.key == toast.key)
+            {
+                *existing = self.build(existing.id, toast);
+                self.dismissed
+                    .borrow_mut()
+                    .push((existing.id, DismissReason::Replaced));
+                return existing.id;
+            }
+        }
+
+        self.insert(toast)
+    }
+
+    // Displays a new toast on-screen as it is, without collapsing it or
+    // replacing a toast with the same key.
+    fn insert(&mut self, toast: Toast<'a, Message>) -> ToastId {
         let id = self.next_toast_id;
         self.next_toast_id = self.next_toast_id.next();
-        self.toasts.borrow_mut().push(toast::Toast {
+        let toast = self.build(id, toast);
+        self.toasts.borrow_mut().push(toast);
+        id
+    }
+
+    /// Updates a toast that is already on-screen, without changing its
+    /// position in the stack. `f` receives a [`Toast`] equivalent to the
+    /// existing toast, and returns the updated toast.
+    ///
+    /// The timer of the toast carries on as before, unless its timeout is
+    /// changed, in which case it restarts with the new timeout.
+    ///
+    /// The action buttons of the toast are kept, and `Toast::action` adds to
+    /// them. Use `Toast::clear_actions` and `Toast::clear_title` to remove the
+    /// action buttons and title of the toast.
+    ///
+    /// # Example
+    /// ```rust
+    /// use iced_toasts::{toast, toast_container, ToastId, ToastLevel};
+    ///
+    /// #[derive(Debug, Clone)]
+    /// enum Message {
+    ///     DismissToast(ToastId),
+    ///     Retry,
+    /// }
+    ///
+    /// let mut toasts = toast_container(Message::DismissToast);
+    /// let id = toasts.push(toast("Syncing...").title("Sync").sticky());
+    ///
+    /// // Once syncing has failed:
+    /// toasts.update(id, |toast| {
+    ///     toast
+    ///         .message("Sync failed")
+    ///         .level(ToastLevel::Error)
+    ///         .clear_actions()
+    ///         .action("Retry", Message::Retry)
+    /// });
+    ///
+    /// // Once syncing has finished:
+    /// toasts.update(id, |toast| {
+    ///     toast
+    ///         .message("Sync complete")
+    ///         .level(ToastLevel::Success)
+    ///         .clear_title()
+    ///         .clear_actions()
+    ///         .timeout(std::time::Duration::from_secs(3))
+    /// });
+    /// ```
+    pub fn update(
+        &mut self,
+        id: ToastId,
+        f: impl FnOnce(Toast<'a, Message>) -> Toast<'a, Message>,
+    ) {
+        let mut toasts = self.toasts.borrow_mut();
+        let Some(existing) = toasts
+            .iter_mut()
+            .find(|toast| toast.id == id && toast.exiting.is_none())
+        else {
+            return;
+        };
+
+        let toast = f(Toast::from_toast(existing));
+        self.replace(existing, toast);
+    }
+
+    // Replaces an existing toast with a [`Toast`], keeping its id, position
+    // and timer.
+    fn replace(&self, existing: &mut toast::Toast<'a, Message>, toast: Toast<'a, Message>) {
+        let mut toast = self.build(existing.id, toast);
+        if toast.timeout == existing.timeout && toast.is_in_progress() == existing.is_in_progress()
+        {
+            toast.expiry = existing.expiry;
+        }
+        toast.count = existing.count;
+        *existing = toast;
+    }
+
+    // Creates the toast that is stored in the container from a [`Toast`].
+    fn build(&self, id: ToastId, toast: Toast<'a, Message>) -> toast::Toast<'a, Message> {
+        let in_progress = toast.loading || toast.progress.is_some();
+        let timeout = self.timeout_of(&toast);
+        toast::Toast {
             id,
-            expiry: time::Instant::now() + self.timeout_duration,
+            // The timer starts once the toast is displayed, and has finished
+            // loading.
+            expiry: timeout
+                .filter(|_| !in_progress)
+                .map_or(toast::Expiry::Never, toast::Expiry::after),
+            timeout,
             level: toast.level,
             title: toast.title,
             message: toast.message,
-            on_dismiss: (self.on_dismiss)(id),
-            action: toast
-                .action
-                .map(|(text, message)| (text.to_string(), message)),
-        });
+            actions: toast.actions,
+            key: toast.key,
+            content: toast.content,
+            loading: toast.loading,
+            progress: toast.progress,
+            percentage: toast.percentage,
+            success_message: toast.success_message,
+            exiting: None,
+            opacity: Rc::new(Cell::new(1.0)),
+            count: 1,
+        }
+    }
+
+    // The amount of time a [`Toast`] is displayed for, or `None` if it is
+    // sticky.
+    fn timeout_of(&self, toast: &Toast<'a, Message>) -> Option<time::Duration> {
+        (!toast.sticky).then(|| toast.timeout.unwrap_or(self.timeout_duration))
+    }
+
+    /// Sets the progress of a toast to `progress`, from 0.0 to 1.0, which
+    /// displays it on the progress bar of the toast. See `Toast::progress`.
+    ///
+    /// If the toast was created with `Toast::success_on_complete`, it turns
+    /// into a success toast once the progress reaches 1.0, which loses its
+    /// spinner if it had one, and starts timing out. Toasts without a progress
+    /// bar are left as they are.
+    pub fn set_progress(&mut self, id: ToastId, progress: f32) {
+        let mut toasts = self.toasts.borrow_mut();
+        let Some(toast) = toasts
+            .iter_mut()
+            .find(|toast| toast.id == id && toast.exiting.is_none() && toast.progress.is_some())
+        else {
+            return;
+        };
+
+        let progress = progress.clamp(0.0, 1.0);
+        if progress >= 1.0
+            && let Some(message) = toast.success_message.take()
+        {
+            toast.message = message;
+            toast.level = Some(ToastLevel::Success);
+            toast.progress = None;
+            toast.percentage = false;
+            toast.loading = false;
+            toast.expiry = toast
+                .timeout
+                .map_or(toast::Expiry::Never, toast::Expiry::after);
+        } else {
+            toast.progress = Some(progress);
+        }
+    }
+
+    /// Displays `loading` as a loading toast while `task` runs, and returns a
+    /// [`Task`] producing the id of the toast along with the result of
+    /// `task`. Once the result reaches `update`, pass it to
+    /// [`ToastContainer::resolve`] to replace the toast in place with the
+    /// toast returned from `on_ok` or `on_err`, which then times out as usual.
+    ///
+    /// The returned [`Task`] must be run by the application for the toast to
+    /// change, typically by mapping its result to a message and returning it
+    /// from `update`. The loading toast is never collapsed into another toast
+    /// or replaced by key.
+    ///
+    /// # Example
+    /// ```rust
+    /// use iced::Task;
+    /// use iced_toasts::{toast, toast_container, ToastContainer, ToastId, ToastLevel};
+    ///
+    /// #[derive(Debug, Clone)]
+    /// enum Message {
+    ///     DismissToast(ToastId),
+    ///     Save,
+    ///     Saved(ToastId, Result<usize, String>),
+    /// }
+    ///
+    /// async fn save() -> Result<usize, String> {
+    ///     Ok(1024)
+    /// }
+    ///
+    /// fn update(toasts: &mut ToastContainer<'_, Message>, message: Message) -> Task<Message> {
+    ///     match message {
+    ///         Message::Save => toasts
+    ///             .promise(
+    ///                 Task::future(save()),
+    ///                 toast("Saving..."),
+    ///                 |bytes| toast(&format!("Saved {bytes} bytes")).level(ToastLevel::Success),
+    ///                 |error| toast(error).title("Failed to save").level(ToastLevel::Error),
+    ///             )
+    ///             .map(|(id, result)| Message::Saved(id, result)),
+    ///         Message::Saved(id, result) => {
+    ///             toasts.resolve(id, &result);
+    ///             Task::none()
+    ///         }
+    ///         Message::DismissToast(_) => Task::none(),
+    ///     }
+    /// }
+    /// ```
+    pub fn promise<T, E>(
+        &mut self,
+        task: Task<Result<T, E>>,
+        loading: Toast<'a, Message>,
+        on_ok: impl Fn(&T) -> Toast<'a, Message> + 'a,
+        on_err: impl Fn(&E) -> Toast<'a, Message> + 'a,
+    ) -> Task<(ToastId, Result<T, E>)>
+    where
+        T: Send + 'static,
+        E: Send + 'static,
+    {
+        let id = self.insert(loading.loading());
+
+        let resolve = move |_: &ToastData<'a, Message>, result: &dyn Any| match result
+            .downcast_ref::<Result<T, E>>()
+        {
+            Some(Ok(value)) => Pending::Finished(on_ok(value)),
+            Some(Err(error)) => Pending::Finished(on_err(error)),
+            None => Pending::Waiting,
+        };
+        self.pending.push((id, Box::new(resolve)));
+
+        task.map(move |result| (id, result))
+    }
+
+    /// Displays `toast` while `stream` runs, and returns a [`Task`] producing
+    /// the id of the toast along with the items of `stream` wrapped in `Some`,
+    /// followed by `None` once `stream` has ended. Once each of them reaches
+    /// `update`, pass it to [`ToastContainer::resolve`] to replace the toast
+    /// in place with the toast returned from `on_item`, such as a progress
+    /// toast with the latest progress, or a success or error toast once the
+    /// stream has finished.
+    ///
+    /// `toast` is displayed as a loading toast unless it has a progress bar,
+    /// so that it does not time out while `stream` runs. It is never collapsed
+    /// into another toast or replaced by key. Once `stream` ends, a toast
+    /// which is still loading or in progress is turned into a regular toast
+    /// with `Toast::finish`, so that it times out as usual.
+    ///
+    /// Like [`ToastContainer::promise`], the returned [`Task`] must be run by
+    /// the application for the toast to change, including the final `None`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use iced::{Task, futures::channel::mpsc};
+    /// use iced_toasts::{toast, toast_container, ToastContainer, ToastId, ToastLevel};
+    ///
+    /// #[derive(Debug, Clone)]
+    /// enum Export {
+    ///     Progress(f32),
+    ///     Finished,
+    ///     Failed(String),
+    /// }
+    ///
+    /// #[derive(Debug, Clone)]
+    /// enum Message {
+    ///     DismissToast(ToastId),
+    ///     Exported(ToastId, Option<Export>),
+    /// }
+    ///
+    /// fn start_export(
+    ///     toasts: &mut ToastContainer<'_, Message>,
+    ///     receiver: mpsc::UnboundedReceiver<Export>,
+    /// ) -> Task<Message> {
+    ///     toasts
+    ///         .stream(receiver, toast("Exporting...").progress(0.0), |export| {
+    ///             match export {
+    ///                 Export::Progress(progress) => toast("Exporting...").progress(*progress),
+    ///                 Export::Finished => toast("Export complete").level(ToastLevel::Success),
+    ///                 Export::Failed(error) => toast(error).level(ToastLevel::Error),
+    ///             }
+    ///         })
+    ///         .map(|(id, export)| Message::Exported(id, export))
+    /// }
+    ///
+    /// fn update(toasts: &mut ToastContainer<'_, Message>, message: Message) {
+    ///     match message {
+    ///         Message::Exported(id, export) => toasts.resolve(id, &export),
+    ///         Message::DismissToast(_) => {}
+    ///     }
+    /// }
+    /// ```
+    pub fn stream<T>(
+        &mut self,
+        stream: impl Stream<Item = T> + Send + 'static,
+        toast: Toast<'a, Message>,
+        on_item: impl Fn(&T) -> Toast<'a, Message> + 'a,
+    ) -> Task<(ToastId, Option<T>)>
+    where
+        T: Send + 'static,
+    {
+        let toast = if toast.progress.is_none() {
+            toast.loading()
+        } else {
+            toast
+        };
+        let id = self.insert(toast);
+
+        let resolve = move |existing: &ToastData<'a, Message>, item: &dyn Any| match item
+            .downcast_ref::<Option<T>>()
+        {
+            Some(Some(item)) => Pending::Updated(on_item(item)),
+            Some(None) => Pending::Finished(Toast::from_toast(existing).finish()),
+            None => Pending::Waiting,
+        };
+        self.pending.push((id, Box::new(resolve)));
+
+        let stream = stream.map(Some).chain(stream::once(async { None }));
+        Task::run(stream, move |item| (id, item))
+    }
+
+    /// Replaces the toast of a promise or stream with the result its task
+    /// produced, once the result reaches `update`. `id` and `result` are the
+    /// id and result produced by the [`Task`] returned from
+    /// [`ToastContainer::promise`] or [`ToastContainer::stream`].
+    ///
+    /// Ids which are not waiting for a result, such as the ids of toasts which
+    /// have already been dismissed, are ignored.
+    pub fn resolve<R: 'static>(&mut self, id: ToastId, result: &R) {
+        let Some(index) = self.pending.iter().position(|(pending, _)| *pending == id) else {
+            return;
+        };
+        let mut toasts = self.toasts.borrow_mut();
+        let Some(existing) = toasts
+            .iter_mut()
+            .find(|toast| toast.id == id && toast.exiting.is_none())
+        else {
+            // The toast has already been dismissed.
+            self.pending.retain(|(pending, _)| *pending != id);
+            return;
+        };
+
+        match (self.pending[index].1)(existing, result) {
+            Pending::Waiting => {}
+            Pending::Updated(toast) => self.replace(existing, toast),
+            Pending::Finished(toast) => {
+                self.replace(existing, toast);
+                self.pending.retain(|(pending, _)| *pending != id);
+            }
+        }
     }
 
-    /// Dismisses a toast. Should be called with the corresponding [`ToastId`]
-    /// whenever the `on_dismiss` message is received.
+    /// Dismisses a toast, such as once the task it describes has finished. The
+    /// `on_dismiss` message is then broadcast with
+    /// [`DismissReason::Programmatic`].
+    ///
+    /// Toasts that time out or are closed by the user are removed
+    /// automatically, so there is no need to call this when the `on_dismiss`
+    /// message is received. Dismissing a toast that has already been removed,
+    /// or is already animating out, does nothing.
     pub fn dismiss(&mut self, id: ToastId) {
-        self.toasts.borrow_mut().retain(|toast| toast.id != id);
+        let mut toasts = self.toasts.borrow_mut();
+        if let Some(toast) = toasts
+            .iter_mut()
+            .find(|toast| toast.id == id && toast.exiting.is_none())
+        {
+            toast.exiting = Some(time::Instant::now());
+            self.dismissed
+                .borrow_mut()
+                .push((id, DismissReason::Programmatic));
+        }
     }
 
     /// Creates the [`Element`] for the [`ToastContainer`] to be used in the
@@ -394,15 +1346,7 @@ where
     /// }
     /// ```
     pub fn view(&self, content: impl Into<Element<'a, Message>>) -> Element<'a, Message> {
-        Element::new(ToastWidget::<'a, Message>::new(
-            self.toasts.clone(),
-            content,
-            self.on_dismiss.clone(),
-            self.alignment_x,
-            self.alignment_y,
-            self.text_size,
-            self.style_fn.clone(),
-        ))
+        Element::new(ToastWidget::<'a, Message>::new(self, content))
     }
 }
 
@@ -418,54 +1362,228 @@ where
             .field("alignment_x", &self.alignment_x)
             .field("alignment_y", &self.alignment_y)
             .field("text_size", &self.text_size)
+            .field("hover", &self.hover)
+            .field("max_visible", &self.max_visible)
+            .field("collapse_duplicates", &self.collapse_duplicates)
+            .field("countdown", &self.countdown)
+            .field("deck", &self.deck)
+            .field("swipe_to_dismiss", &self.swipe_to_dismiss)
+            .field("animation", &self.animation)
+            .field("view_toast", &self.view_toast.is_some())
             .finish()
     }
 }
 
+// Produces the message that is broadcast when a toast is dismissed.
+type OnDismiss<'a, Message> = Rc<Box<dyn Fn(ToastId, DismissReason) -> Message + 'a>>;
+
+// Produces the toast a promise or stream toast is replaced with, once its
+// task has produced a result. It receives the toast as it is now, and the
+// result.
+type Resolve<'a, Message> =
+    Box<dyn FnMut(&ToastData<'a, Message>, &dyn Any) -> Pending<Toast<'a, Message>> + 'a>;
+
+// The state of the task behind a promise or stream toast.
+enum Pending<Toast> {
+    // The task has not produced anything new.
+    Waiting,
+    // The task has produced a new item, and may produce more.
+    Updated(Toast),
+    // The task has produced its result, or has ended.
+    Finished(Toast),
+}
+
+// Produces the element of a toast in place of the default appearance.
+type ViewToast<'a, Message> =
+    Rc<dyn Fn(&ToastData<'a, Message>, StyleFn<'a>) -> Element<'a, ToastEvent<Message>> + 'a>;
+
+// Determines how toasts react to the mouse hovering over them.
+#[derive(Copy, Clone, Debug)]
+struct Hover {
+    pause: bool,
+    grace: time::Duration,
+    scope: HoverScope,
+}
+
+// The number of toasts which peek out from behind the newest toast in a deck.
+const DECK_DEPTH: usize = 2;
+// The distance each toast peeks out from behind the toast in front of it.
+const DECK_PEEK: f32 = 10.0;
+// How much smaller each toast is than the toast in front of it.
+const DECK_SCALE: f32 = 0.05;
+
+// The distance the pointer must move before a pressed toast starts following it.
+const SWIPE_SLOP: f32 = 8.0;
+// The distance a toast must be dragged to be dismissed when it is let go of.
+const SWIPE_DISTANCE: f32 = 100.0;
+// The speed in pixels per second at which a toast is dismissed when it is let
+// go of, no matter how far it was dragged.
+const SWIPE_VELOCITY: f32 = 500.0;
+// Pausing for longer than this before letting go of a toast cancels out the
+// speed it was dragged at.
+const SWIPE_IDLE: time::Duration = time::Duration::from_millis(100);
+
+// Determines how toasts animate as they appear and disappear.
+#[derive(Copy, Clone, Debug)]
+struct Animation {
+    transition: Transition,
+    duration: time::Duration,
+    easing: Easing,
+    reduced_motion: bool,
+}
+
+impl Animation {
+    // The eased progress of an animation which started at `start`, from 0.0
+    // to 1.0.
+    fn progress(&self, start: time::Instant, now: time::Instant) -> f32 {
+        if self.duration.is_zero() {
+            return 1.0;
+        }
+        let elapsed = now.saturating_duration_since(start).as_secs_f32();
+        self.easing
+            .value((elapsed / self.duration.as_secs_f32()).min(1.0))
+    }
+
+    // Whether an animation which started at `start` has finished.
+    fn is_finished(&self, start: time::Instant, now: time::Instant) -> bool {
+        now.saturating_duration_since(start) >= self.duration
+    }
+}
+
+/// Determines how toasts are animated as they appear and disappear.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Transition {
+    /// Toasts slide in from the edge of the screen, and slide back out.
+    Slide,
+    /// Toasts fade in and out.
+    Fade,
+    /// Toasts slide and fade in and out.
+    SlideFade,
+}
+
+/// Determines which toasts have their timers paused when the mouse hovers over
+/// the toasts.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum HoverScope {
+    /// Hovering over any toast pauses every toast in the stack.
+    Stack,
+    /// Hovering over a toast only pauses that toast, while the rest keep
+    /// counting down.
+    Toast,
+}
+
 // TODO: Move `ToastWidget` to it's own file
 
 // The [`Widget`] reponsible for displaying toasts. It is responsible for expiring
 // toasts at the correct time.
 struct ToastWidget<'a, Message> {
     content: Element<'a, Message>,
-    toasts: Rc<RefCell<Vec<toast::Toast<Message>>>>,
-    toast_elements: Vec<Element<'a, Message>>,
+    toasts: Rc<RefCell<Vec<toast::Toast<'a, Message>>>>,
+    // The ids of the toasts each element in `toast_elements` was created from.
+    toast_ids: Vec<ToastId>,
+    toast_elements: Vec<Element<'a, toast::Event<Message>>>,
 
-    on_dismiss: Rc<Box<dyn Fn(ToastId) -> Message + 'a>>,
+    dismissed: Rc<RefCell<Vec<(ToastId, DismissReason)>>>,
+    on_dismiss: OnDismiss<'a, Message>,
 
     alignment_x: alignment::Horizontal,
     alignment_y: alignment::Vertical,
+    hover: Hover,
+    max_visible: Option<usize>,
+    countdown: bool,
+    deck: bool,
+    swipe_to_dismiss: bool,
+    animation: Animation,
+
+    text_size: Pixels,
+    style_fn: StyleFn<'a>,
+}
+
+// The persistent state of the toast stack.
+#[derive(Default)]
+struct StackState {
+    // Whether the user has expanded the stack past its maximum size.
+    expanded: bool,
+    // The number of toasts that were displayed in the last layout.
+    visible: usize,
+    // The number of toasts waiting in the queue in the last layout.
+    hidden: usize,
+    // Whether expanding the stack would display more toasts, as toasts are
+    // only queued because of the maximum set with `max_visible`.
+    expandable: bool,
+    // The time of the latest event, used to draw the countdown bars and
+    // animations.
+    now: Option<time::Instant>,
+    // The time each toast was first displayed at, used to animate it in.
+    entered: HashMap<ToastId, time::Instant>,
+    // Whether the mouse is over the stack, which expands the deck.
+    hovered: bool,
+    // The toast currently being dragged, if any.
+    swipe: Option<Swipe>,
+    // The offset of each toast when it was let go of and the time it was let
+    // go at, used to snap it back into place or keep it offset as it exits.
+    released: HashMap<ToastId, (f32, time::Instant)>,
+}
+
+// A toast being dragged sideways by the mouse or a finger.
+struct Swipe {
+    id: ToastId,
+    // The finger dragging the toast, or `None` for the mouse.
+    finger: Option<touch::Finger>,
+    // The horizontal position the toast was pressed at.
+    start: f32,
+    // How far the pointer has moved since the toast was pressed.
+    offset: f32,
+    // Whether the pointer has moved far enough for the toast to follow it.
+    dragging: bool,
+    // The speed of the pointer in pixels per second, and the time it last
+    // moved at.
+    velocity: f32,
+    moved: time::Instant,
 }
 
 impl<'a, Message> ToastWidget<'a, Message>
 where
-    Message: 'a + Clone,
+    Message: 'a + Clone + std::fmt::Debug,
 {
     fn new(
-        toasts: Rc<RefCell<Vec<toast::Toast<Message>>>>,
+        container: &ToastContainer<'a, Message>,
         content: impl Into<Element<'a, Message>>,
-        on_dismiss: Rc<Box<dyn Fn(ToastId) -> Message + 'a>>,
-        alignment_x: alignment::Horizontal,
-        alignment_y: alignment::Vertical,
-        text_size: Pixels,
-        style_fn: StyleFn<'a>,
     ) -> Self {
-        let mut toast_elements: Vec<_> = toasts
-            .borrow()
+        let toasts = container.toasts.borrow();
+        let toast_ids = toasts.iter().map(|toast| toast.id).collect();
+        let toast_elements = toasts
             .iter()
-            .map(|toast| toast.view(text_size, style_fn.clone()))
+            .map(|toast| {
+                let style_fn = container.style_fn.fade(toast.opacity.clone());
+                match &container.view_toast {
+                    Some(view_toast) => view_toast(toast, style_fn),
+                    None => toast.view(
+                        container.text_size,
+                        container.animation.reduced_motion,
+                        style_fn,
+                    ),
+                }
+            })
             .collect();
-        if alignment_y == alignment::Vertical::Top {
-            toast_elements.reverse()
-        }
 
         ToastWidget {
             content: content.into(),
-            toasts,
+            toasts: container.toasts.clone(),
+            toast_ids,
             toast_elements,
-            on_dismiss,
-            alignment_x,
-            alignment_y,
+            dismissed: container.dismissed.clone(),
+            on_dismiss: container.on_dismiss.clone(),
+            alignment_x: container.alignment_x,
+            alignment_y: container.alignment_y,
+            hover: container.hover,
+            max_visible: container.max_visible,
+            countdown: container.countdown,
+            deck: container.deck,
+            swipe_to_dismiss: container.swipe_to_dismiss,
+            animation: container.animation,
+            text_size: container.text_size,
+            style_fn: container.style_fn.clone(),
         }
     }
 }
@@ -503,25 +1621,25 @@ impl<Message> Widget<Message, Theme, Renderer> for ToastWidget<'_, Message> {
     }
 
     fn tag(&self) -> Tag {
-        Tag::stateless()
+        Tag::of::<StackState>()
     }
 
     fn state(&self) -> State {
-        State::None
+        State::new(StackState::default())
     }
 
     fn children(&self) -> Vec<Tree> {
-        std::iter::once(Tree::new(&self.content))
-            .chain(self.toast_elements.iter().map(Tree::new))
-            .collect()
+        // The toasts produce different messages to the content, so their
+        // states are kept together in a separate tree.
+        let mut toasts = Tree::empty();
+        toasts.children = self.toast_elements.iter().map(Tree::new).collect();
+
+        vec![Tree::new(&self.content), toasts]
     }
 
     fn diff(&self, tree: &mut Tree) {
-        tree.diff_children(
-            &std::iter::once(&self.content)
-                .chain(self.toast_elements.iter())
-                .collect::<Vec<_>>(),
-        );
+        tree.children[0].diff(&self.content);
+        tree.children[1].diff_children(&self.toast_elements);
     }
 
     fn operate(
@@ -553,21 +1671,60 @@ impl<Message> Widget<Message, Theme, Renderer> for ToastWidget<'_, Message> {
         shell: &mut Shell<'_, Message>,
         viewport: &Rectangle,
     ) {
+        // Let the application know about any toasts that were dismissed by
+        // the `ToastContainer` itself.
+        self.dismissed
+            .borrow_mut()
+            .drain(..)
+            .for_each(|(id, reason)| shell.publish((self.on_dismiss)(id, reason)));
+
         if let Event::Window(window::Event::RedrawRequested(now)) = &event {
-            self.toasts
-                .borrow()
-                .iter()
-                .for_each(|&toast::Toast { id, expiry, .. }| {
-                    if now > &expiry {
-                        shell.publish((self.on_dismiss)(id));
-                    } else {
-                        // If we do not expire a toast now, we guarantee that
-                        // there will be another redraw request at the time
-                        // the toast expires, so that this handler will be
-                        // called again at that time.
-                        shell.request_redraw_at(expiry);
-                    }
-                });
+            // Toasts which have finished animating out are removed, and the
+            // toast elements need to be rebuilt now that they are gone.
+            let mut toasts = self.toasts.borrow_mut();
+            let len = toasts.len();
+            toasts.retain(|toast| {
+                toast
+                    .exiting
+                    .is_none_or(|exiting| !self.animation.is_finished(exiting, *now))
+            });
+            if toasts.len() < len {
+                shell.invalidate_widgets();
+            }
+            if toasts.iter().any(|toast| toast.exiting.is_some()) {
+                shell.request_redraw();
+            }
+            drop(toasts);
+
+            let mut expired = Vec::new();
+            self.toasts.borrow().iter().for_each(|toast| {
+                // Sticky, paused and exiting toasts cannot expire right
+                // now.
+                let (toast::Expiry::At(expiry), None) = (toast.expiry, toast.exiting) else {
+                    return;
+                };
+                let id = toast.id;
+
+                if now > &expiry {
+                    expired.push(id);
+                } else {
+                    // If we do not expire a toast now, we guarantee that
+                    // there will be another redraw request at the time
+                    // the toast expires, so that this handler will be
+                    // called again at that time.
+                    shell.request_redraw_at(expiry);
+                }
+            });
+
+            for id in expired {
+                dismiss(
+                    &self.toasts,
+                    id,
+                    DismissReason::Timeout,
+                    &self.on_dismiss,
+                    shell,
+                );
+            }
         }
 
         self.content.as_widget_mut().update(
@@ -607,9 +1764,16 @@ impl<Message> Widget<Message, Theme, Renderer> for ToastWidget<'_, Message> {
         viewport: &Rectangle,
         translation: iced::Vector,
     ) -> Option<overlay::Element<'a, Message, Theme, Renderer>> {
-        let (content_state, toast_state) = state.children.split_at_mut(1);
+        let Tree {
+            state: stack_state,
+            children,
+            ..
+        } = state;
+        let [content_state, toast_state] = children.as_mut_slice() else {
+            unreachable!("toast widget should have a content tree and a toast tree")
+        };
         let content_overlay = self.content.as_widget_mut().overlay(
-            &mut content_state[0],
+            content_state,
             layout,
             renderer,
             viewport,
@@ -617,14 +1781,25 @@ impl<Message> Widget<Message, Theme, Renderer> for ToastWidget<'_, Message> {
         );
 
         let toast_overlay = (!self.toasts.borrow().is_empty()).then(|| {
-            let toast_overlay = Overlay::new(
-                self.toasts.clone(),
-                &mut self.toast_elements,
-                toast_state,
-                layout.bounds().position() + translation,
-                self.alignment_x,
-                self.alignment_y,
-            );
+            let toast_overlay = Overlay {
+                toasts: self.toasts.clone(),
+                ids: &self.toast_ids,
+                elements: &mut self.toast_elements,
+                state: &mut toast_state.children,
+                on_dismiss: self.on_dismiss.clone(),
+                position: layout.bounds().position() + translation,
+                alignment_x: self.alignment_x,
+                alignment_y: self.alignment_y,
+                hover: self.hover,
+                max_visible: self.max_visible,
+                countdown: self.countdown,
+                deck: self.deck,
+                swipe_to_dismiss: self.swipe_to_dismiss,
+                animation: self.animation,
+                stack: stack_state.downcast_mut::<StackState>(),
+                text_size: self.text_size,
+                style_fn: self.style_fn.clone(),
+            };
             overlay::Element::new(Box::new(toast_overlay))
         });
 
@@ -636,52 +1811,435 @@ impl<Message> Widget<Message, Theme, Renderer> for ToastWidget<'_, Message> {
     }
 }
 
+// The overlay responsible for laying out and displaying toasts on top of the
+// application. Only as many toasts as will fit on-screen are displayed, and the
+// rest wait in a queue until there is room for them. While toasts are queued,
+// a summary row is displayed, which expands the stack when clicked if toasts
+// are only queued because of `max_visible`, and otherwise displays the next
+// batch of toasts.
 struct Overlay<'a, 'b, Message> {
-    toasts: Rc<RefCell<Vec<toast::Toast<Message>>>>,
-    elements: &'b mut [Element<'a, Message>],
+    toasts: Rc<RefCell<Vec<toast::Toast<'a, Message>>>>,
+    ids: &'b [ToastId],
+    elements: &'b mut [Element<'a, toast::Event<Message>>],
     state: &'b mut [Tree],
+    on_dismiss: OnDismiss<'a, Message>,
 
     position: Point,
     alignment_x: alignment::Horizontal,
     alignment_y: alignment::Vertical,
+    hover: Hover,
+    max_visible: Option<usize>,
+    countdown: bool,
+    deck: bool,
+    swipe_to_dismiss: bool,
+    animation: Animation,
+    stack: &'b mut StackState,
+
+    text_size: Pixels,
+    style_fn: StyleFn<'a>,
 }
 
-impl<'a, 'b, Message> Overlay<'a, 'b, Message> {
-    fn new(
-        toasts: Rc<RefCell<Vec<toast::Toast<Message>>>>,
-        elements: &'b mut [Element<'a, Message>],
-        state: &'b mut [Tree],
-        position: Point,
-        alignment_x: alignment::Horizontal,
-        alignment_y: alignment::Vertical,
-    ) -> Self {
-        Overlay {
-            toasts,
-            elements,
-            state,
-            position,
-            alignment_x,
-            alignment_y,
+impl<Message> Overlay<'_, '_, Message> {
+    // The text displayed in the summary row when `hidden` toasts are queued.
+    fn summary_text(&self, hidden: usize, bounds: Size) -> Text {
+        Text {
+            content: if hidden == 1 {
+                "+1 more notification".to_string()
+            } else {
+                format!("+{hidden} more notifications")
+            },
+            bounds,
+            size: self.text_size,
+            line_height: text::LineHeight::default(),
+            font: iced::Font::DEFAULT,
+            align_x: text::Alignment::Center,
+            align_y: iced::alignment::Vertical::Center,
+            shaping: text::Shaping::Basic,
+            wrapping: text::Wrapping::None,
+        }
+    }
+
+    // The size of the summary row when `hidden` toasts are queued.
+    fn summary_size(&self, hidden: usize, max: Size) -> Size {
+        let text = self.summary_text(hidden, max);
+        let paragraph = <Renderer as text::Renderer>::Paragraph::with_text(
+            text.with_content(text.content.as_str()),
+        );
+        paragraph.min_bounds().expand(Padding::from([8, 16]))
+    }
+
+    // The offset and opacity of a toast at `now`, as it animates in and out.
+    fn animate(
+        &self,
+        toast: &toast::Toast<'_, Message>,
+        bounds: Rectangle,
+        now: time::Instant,
+    ) -> (Vector, f32) {
+        let entered = self
+            .stack
+            .entered
+            .get(&toast.id)
+            .map_or(0.0, |&entered| self.animation.progress(entered, now));
+        let exited = toast
+            .exiting
+            .map_or(0.0, |exiting| self.animation.progress(exiting, now));
+        let shown = entered.min(1.0 - exited);
+
+        let (slide, fade) = match self.animation.transition {
+            _ if self.animation.reduced_motion => (false, true),
+            Transition::Slide => (true, false),
+            Transition::Fade => (false, true),
+            Transition::SlideFade => (true, true),
+        };
+
+        // Toasts slide in from the closest edge of the screen.
+        let distance = 1.0 - shown;
+        let offset = match (self.alignment_x, self.alignment_y) {
+            _ if !slide => Vector::ZERO,
+            (alignment::Horizontal::Left, _) => Vector::new(-(bounds.width + 5.0) * distance, 0.0),
+            (alignment::Horizontal::Right, _) => Vector::new((bounds.width + 5.0) * distance, 0.0),
+            (alignment::Horizontal::Center, alignment::Vertical::Top) => {
+                Vector::new(0.0, -(bounds.height + 5.0) * distance)
+            }
+            (alignment::Horizontal::Center, alignment::Vertical::Bottom) => {
+                Vector::new(0.0, (bounds.height + 5.0) * distance)
+            }
+        };
+        let opacity = if fade { shown } else { 1.0 };
+
+        (offset, opacity)
+    }
+
+    // The horizontal distance the toast has been dragged by.
+    fn swipe_offset(&self, toast: &toast::Toast<'_, Message>, now: time::Instant) -> f32 {
+        if let Some(swipe) = &self.stack.swipe
+            && swipe.id == toast.id
+            && swipe.dragging
+        {
+            return swipe.offset;
+        }
+
+        // Swiped toasts stay where they were let go of as they exit, while the
+        // others snap back into place.
+        match self.stack.released.get(&toast.id) {
+            Some(&(offset, _)) if toast.exiting.is_some() => offset,
+            Some(&(offset, released)) if !self.animation.reduced_motion => {
+                offset * (1.0 - self.animation.progress(released, now))
+            }
+            _ => 0.0,
         }
     }
+
+    // Starts tracking a toast which was pressed with the mouse or a finger,
+    // so that it can be dragged once the pointer moves.
+    fn press(
+        &mut self,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        now: time::Instant,
+    ) {
+        let (finger, position) = match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                (None, cursor.position())
+            }
+            Event::Touch(touch::Event::FingerPressed { id, position }) => {
+                (Some(*id), Some(*position))
+            }
+            _ => return,
+        };
+        let Some(position) = position else {
+            return;
+        };
+
+        // The newest toast is drawn on top when toasts overlap.
+        let pressed = layout
+            .children()
+            .take(self.stack.visible)
+            .zip(self.ids)
+            .enumerate()
+            .rev()
+            .find(|(index, (layout, id))| {
+                layout.bounds().contains(position)
+                    && !self.is_exiting(**id)
+                    && self.is_interactive(*index)
+            });
+        if let Some((_, (_, &id))) = pressed {
+            self.stack.swipe = Some(Swipe {
+                id,
+                finger,
+                start: position.x,
+                offset: 0.0,
+                dragging: false,
+                velocity: 0.0,
+                moved: now,
+            });
+        }
+    }
+
+    // Drags the pressed toast sideways as the pointer moves, and dismisses it
+    // once it is let go of after being dragged far or fast enough.
+    fn swipe(
+        &mut self,
+        event: &Event,
+        cursor: mouse::Cursor,
+        now: time::Instant,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let (finger, position) = match event {
+            Event::Mouse(_) => (None, cursor.position()),
+            Event::Touch(
+                touch::Event::FingerMoved { id, position }
+                | touch::Event::FingerLifted { id, position }
+                | touch::Event::FingerLost { id, position },
+            ) => (Some(*id), Some(*position)),
+            _ => return,
+        };
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                let alignment_x = self.alignment_x;
+                let (Some(swipe), Some(position)) = (&mut self.stack.swipe, position) else {
+                    return;
+                };
+                let offset = constrain_swipe(alignment_x, position.x - swipe.start);
+                if swipe.finger != finger || !swipe.dragging && offset.abs() < SWIPE_SLOP {
+                    return;
+                }
+
+                let elapsed = now.saturating_duration_since(swipe.moved).as_secs_f32();
+                if elapsed > 0.0 {
+                    swipe.velocity = (offset - swipe.offset) / elapsed;
+                }
+                swipe.offset = offset;
+                swipe.moved = now;
+                swipe.dragging = true;
+
+                shell.capture_event();
+                shell.request_redraw();
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. }) => {
+                let Some(swipe) = self.stack.swipe.take_if(|swipe| swipe.finger == finger) else {
+                    return;
+                };
+                if !swipe.dragging {
+                    return;
+                }
+
+                let idle = now.saturating_duration_since(swipe.moved);
+                let is_swiped = is_swiped(swipe.offset, swipe.velocity, idle);
+                let is_lost = matches!(event, Event::Touch(touch::Event::FingerLost { .. }));
+                if is_swiped && !is_lost {
+                    dismiss(
+                        &self.toasts,
+                        swipe.id,
+                        DismissReason::Swiped,
+                        &self.on_dismiss,
+                        shell,
+                    );
+                }
+                self.stack.released.insert(swipe.id, (swipe.offset, now));
+
+                // Letting go of a toast should not also press the button
+                // under the pointer.
+                shell.capture_event();
+                shell.request_redraw();
+            }
+            _ => {}
+        }
+    }
+
+    // Whether the toasts are displayed as a deck right now.
+    fn is_deck(&self) -> bool {
+        self.deck && !self.stack.hovered
+    }
+
+    // Whether the toast at `index` in the stack can be interacted with. In a
+    // deck, only the newest toast can be.
+    fn is_interactive(&self, index: usize) -> bool {
+        !self.is_deck() || index + 1 == self.stack.visible
+    }
+
+    // Whether the toast with the given id is animating out.
+    fn is_exiting(&self, id: ToastId) -> bool {
+        self.toasts
+            .borrow()
+            .iter()
+            .any(|toast| toast.id == id && toast.exiting.is_some())
+    }
+
+    // The layout of the summary row, if any toasts are queued.
+    fn summary_layout<'c>(&self, layout: Layout<'c>) -> Option<Layout<'c>> {
+        (self.stack.hidden > 0)
+            .then(|| layout.children().nth(self.stack.visible))
+            .flatten()
+    }
 }
 
 impl<'a, Message> overlay::Overlay<Message, Theme, Renderer> for Overlay<'a, '_, Message> {
     fn layout(&mut self, renderer: &Renderer, bounds: Size) -> Node {
-        layout::flex::resolve(
-            Axis::Vertical,
-            renderer,
-            &Limits::new(Size::ZERO, bounds),
-            Length::Shrink,
-            Length::Shrink,
-            Padding::from(5),
-            5.0,
-            self.alignment_x.into(),
-            self.elements,
-            self.state,
-        )
-        .translate(Vector::new(self.position.x, self.position.y))
-        .align(self.alignment_x.into(), self.alignment_y.into(), bounds)
+        let padding = Padding::from(5);
+        let spacing = 5.0;
+        let limits = Limits::new(Size::ZERO, bounds).shrink(padding);
+
+        let max_visible = self.max_visible.unwrap_or(usize::MAX);
+        // Once the queue is gone, the stack goes back to its maximum size.
+        if self.elements.len() <= max_visible {
+            self.stack.expanded = false;
+        }
+        let max_visible = if self.stack.expanded {
+            usize::MAX
+        } else {
+            max_visible
+        };
+
+        // Toasts which are animating out take up less and less space, so that
+        // the toasts around them slide into the space they leave behind. With
+        // reduced motion, they take up their full space until they are gone.
+        let now = time::Instant::now();
+        let collapse: Vec<f32> = {
+            let toasts = self.toasts.borrow();
+            self.ids
+                .iter()
+                .map(|&id| {
+                    toasts
+                        .iter()
+                        .find(|toast| toast.id == id)
+                        .and_then(|toast| toast.exiting)
+                        .filter(|_| !self.animation.reduced_motion)
+                        .map_or(1.0, |exiting| 1.0 - self.animation.progress(exiting, now))
+                })
+                .collect()
+        };
+
+        // The summary row is a single line, so its height does not depend on
+        // the number of toasts it counts.
+        let summary_height = self.summary_size(self.elements.len(), limits.max()).height;
+
+        // Toasts are only laid out until they no longer fit.
+        let mut children: Vec<Node> = Vec::new();
+        let (elements, state) = (&mut *self.elements, &mut *self.state);
+        let fit = fit(
+            elements.len(),
+            max_visible,
+            limits.max().height,
+            spacing,
+            summary_height,
+            |index| {
+                let node =
+                    elements[index]
+                        .as_widget_mut()
+                        .layout(&mut state[index], renderer, &limits);
+                let height = (spacing + node.size().height) * collapse[index];
+                children.push(node);
+                height
+            },
+        );
+        children.truncate(fit.visible);
+        let mut height = fit.height;
+
+        self.stack.visible = fit.visible;
+        self.stack.hidden = self.elements.len() - fit.visible;
+        self.stack.expandable = !fit.is_full && !self.stack.expanded;
+
+        // The summary row is the full width of the stack, and is placed as if
+        // it was the newest toast.
+        if self.stack.hidden > 0 {
+            let size = self.summary_size(self.stack.hidden, limits.max());
+            let width = children
+                .iter()
+                .map(|child| child.size().width)
+                .fold(size.width, f32::max);
+            height += spacing + size.height;
+            children.push(Node::new(Size::new(width, size.height)));
+        }
+
+        let width = children
+            .iter()
+            .map(|child| child.size().width)
+            .fold(0.0, f32::max);
+
+        let alignment_x = self.alignment_x;
+        let x = |size: Size| {
+            padding.left
+                + match alignment_x {
+                    alignment::Horizontal::Left => 0.0,
+                    alignment::Horizontal::Center => (width - size.width) / 2.0,
+                    alignment::Horizontal::Right => width - size.width,
+                }
+        };
+
+        // At rest, a deck displays the newest toast in front, with the older
+        // toasts peeking out from behind it.
+        if self.is_deck() {
+            let (toasts, summary) = children.split_at_mut(self.stack.visible);
+            let front = toasts.last().map_or(0.0, |toast| toast.size().height);
+            let peeking = toasts.len().saturating_sub(1).min(DECK_DEPTH);
+            let deck = front + DECK_PEEK * peeking as f32;
+            let summary_height = summary
+                .first()
+                .map_or(0.0, |summary| spacing + summary.size().height);
+
+            // The summary row is placed on the far side of the deck.
+            let (deck_y, summary_y) = match self.alignment_y {
+                alignment::Vertical::Top => (padding.top, padding.top + deck + spacing),
+                alignment::Vertical::Bottom => (padding.top + summary_height, padding.top),
+            };
+            for (depth, toast) in toasts.iter_mut().rev().enumerate() {
+                let size = toast.size();
+                let peek = DECK_PEEK * depth.min(DECK_DEPTH) as f32;
+                let y = match self.alignment_y {
+                    alignment::Vertical::Top => deck_y + front + peek - size.height,
+                    alignment::Vertical::Bottom => deck_y + deck - front - peek,
+                };
+                toast.move_to_mut(Point::new(x(size), y));
+            }
+            if let Some(summary) = summary.first_mut() {
+                summary.move_to_mut(Point::new(x(summary.size()), summary_y));
+            }
+
+            return Node::with_children(
+                Size::new(width, deck + summary_height).expand(padding),
+                children,
+            )
+            .translate(Vector::new(self.position.x, self.position.y))
+            .align(self.alignment_x.into(), self.alignment_y.into(), bounds);
+        }
+
+        // The newest toast is displayed closest to the edge of the screen.
+        let mut y = padding.top;
+        let mut place = |(child, collapse): (&mut Node, &f32)| {
+            let size = child.size();
+            // A collapsing toast stays anchored to the edge of the screen, as
+            // the toasts further away move over it.
+            let collapsed = (size.height + spacing) * (1.0 - collapse);
+            let y_offset = match self.alignment_y {
+                alignment::Vertical::Top => 0.0,
+                alignment::Vertical::Bottom => -collapsed,
+            };
+            child.move_to_mut(Point::new(x(size), y + y_offset));
+            y += (size.height + spacing) * collapse;
+        };
+        // The summary row never collapses.
+        let collapse: Vec<f32> = collapse[..self.stack.visible]
+            .iter()
+            .copied()
+            .chain((self.stack.hidden > 0).then_some(1.0))
+            .collect();
+        match self.alignment_y {
+            alignment::Vertical::Top => children
+                .iter_mut()
+                .rev()
+                .zip(collapse.iter().rev())
+                .for_each(&mut place),
+            alignment::Vertical::Bottom => children.iter_mut().zip(&collapse).for_each(&mut place),
+        }
+
+        Node::with_children(Size::new(width, height).expand(padding), children)
+            .translate(Vector::new(self.position.x, self.position.y))
+            .align(self.alignment_x.into(), self.alignment_y.into(), bounds)
     }
 
     fn draw(
@@ -694,38 +2252,144 @@ impl<'a, Message> overlay::Overlay<Message, Theme, Renderer> for Overlay<'a, '_,
     ) {
         let viewport = layout.bounds();
 
-        // Reverse the iterator depending on whether the toasts display at the top
-        // of the screen or the bottom. Ideally, I'd just reverse the iterator only
-        // but I can't since zips can't be reversed, and the iterators are
-        // different types, so you get this ugly piece of code duplication.
-        if self.alignment_y == alignment::Vertical::Bottom {
-            let toast_iterator = self
-                .elements
-                .iter()
-                .rev()
-                .zip(self.state.iter().rev())
-                .zip(layout.children().rev());
-            for ((child, state), layout) in toast_iterator {
-                child
-                    .as_widget()
-                    .draw(state, renderer, theme, style, layout, cursor, &viewport)
-            }
-        } else {
-            let toast_iterator = self
-                .elements
-                .iter()
-                .zip(self.state.iter())
-                .zip(layout.children());
-            for ((child, state), layout) in toast_iterator {
-                child
-                    .as_widget()
-                    .draw(state, renderer, theme, style, layout, cursor, &viewport)
-            }
+        let now = self.stack.now.unwrap_or_else(time::Instant::now);
+        let toast_style = self.style_fn.0(theme);
+        let toasts = self.toasts.borrow();
+
+        // Toasts that did not fit have no layout, so they are skipped here.
+        // Toasts which are animating out are drawn first, so that the toasts
+        // sliding into their space are drawn over them.
+        let mut children: Vec<_> = self
+            .elements
+            .iter()
+            .zip(self.state.iter())
+            .zip(layout.children().take(self.stack.visible))
+            .zip(self.ids)
+            .enumerate()
+            .filter_map(|(index, (((child, state), layout), &id))| {
+                let toast = toasts.iter().find(|toast| toast.id == id)?;
+                Some((index, child, state, layout, toast))
+            })
+            .collect();
+        children.sort_by_key(|(.., toast)| toast.exiting.is_none());
+
+        let front = layout
+            .children()
+            .nth(self.stack.visible.saturating_sub(1))
+            .map(|front| front.bounds());
+        children
+            .into_iter()
+            .for_each(|(index, child, state, layout, toast)| {
+                let bounds = layout.bounds();
+                let (mut offset, opacity) = self.animate(toast, bounds, now);
+                // Toasts fade out as they are dragged away, and swiped toasts
+                // keep moving in the direction they were swiped in.
+                let swipe = self.swipe_offset(toast, now);
+                if toast.exiting.is_some() && offset.x * swipe < 0.0 {
+                    offset.x = -offset.x;
+                }
+                let offset = offset + Vector::new(swipe, 0.0);
+                let opacity = opacity * (1.0 - (swipe.abs() / bounds.width).min(1.0) / 2.0);
+                toast.opacity.set(opacity);
+
+                // In a deck, the older toasts are shrunk and only drawn where
+                // they peek out from behind the newest toast.
+                let depth = self.stack.visible - 1 - index;
+                let (clip, scale) = match front {
+                    Some(front) if self.is_deck() && depth > 0 => {
+                        if depth > DECK_DEPTH {
+                            return;
+                        }
+                        let clip = match self.alignment_y {
+                            alignment::Vertical::Top => Rectangle {
+                                y: front.y,
+                                height: (bounds.y + bounds.height - front.y).max(0.0),
+                                ..bounds
+                            },
+                            alignment::Vertical::Bottom => Rectangle {
+                                height: (front.y + front.height - bounds.y).max(0.0),
+                                ..bounds
+                            },
+                        };
+                        let anchor = match self.alignment_y {
+                            alignment::Vertical::Top => {
+                                Point::new(bounds.center_x(), bounds.y + bounds.height)
+                            }
+                            alignment::Vertical::Bottom => Point::new(bounds.center_x(), bounds.y),
+                        };
+                        let scale = Transformation::translate(anchor.x, anchor.y)
+                            * Transformation::scale(1.0 - DECK_SCALE * depth as f32)
+                            * Transformation::translate(-anchor.x, -anchor.y);
+                        (clip, scale)
+                    }
+                    _ => (Rectangle::INFINITE, Transformation::IDENTITY),
+                };
+
+                renderer.with_layer(clip, |renderer| {
+                    renderer.with_transformation(scale, |renderer| {
+                        renderer.with_translation(offset, |renderer| {
+                            child
+                                .as_widget()
+                                .draw(state, renderer, theme, style, layout, cursor, &viewport);
+
+                            let remaining = toast.remaining(now).filter(|_| self.countdown);
+                            if let Some(remaining) = remaining {
+                                // With reduced motion, the bar shrinks in tenths.
+                                let remaining = if self.animation.reduced_motion {
+                                    (remaining * 10.0).ceil() / 10.0
+                                } else {
+                                    remaining
+                                };
+                                let height = 3.0;
+                                let color = toast
+                                    .level
+                                    .and_then(|level| (toast_style.level_to_color)(&level))
+                                    .unwrap_or(theme.extended_palette().primary.base.color);
+                                renderer.fill_quad(
+                                    renderer::Quad {
+                                        bounds: Rectangle {
+                                            x: bounds.x,
+                                            y: bounds.y + bounds.height - height,
+                                            width: bounds.width * remaining,
+                                            height,
+                                        },
+                                        border: Border {
+                                            radius: iced::border::Radius::default()
+                                                .bottom_left(toast_style.border.radius.bottom_left),
+                                            ..Border::default()
+                                        },
+                                        shadow: Shadow::default(),
+                                        snap: false,
+                                    },
+                                    color.scale_alpha(opacity),
+                                );
+                            }
+                        });
+                    });
+                });
+            });
+
+        if let Some(summary) = self.summary_layout(layout) {
+            let bounds = summary.bounds();
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border: toast_style.border,
+                    shadow: toast_style.shadow,
+                    snap: false,
+                },
+                toast_style
+                    .background
+                    .unwrap_or(Background::Color(Color::TRANSPARENT)),
+            );
+            renderer.fill_text(
+                self.summary_text(self.stack.hidden, bounds.size()),
+                bounds.center(),
+                toast_style.text_color.unwrap_or(style.text_color),
+                bounds,
+            );
         }
-        // TODO: Make toasts not draw if they cannot fit. Currently, they
-        // just shrink in size and display some of it's elements. Perhaps
-        // implement a queue system so that cut off toasts still have a
-        // chance to display later.
     }
 
     fn update(
@@ -740,29 +2404,191 @@ impl<'a, Message> overlay::Overlay<Message, Theme, Renderer> for Overlay<'a, '_,
         // This function will always be called right before
         // `ToastWidget::update`. This means that right before a toast is
         // considered for expiry as part of as `RedrawRequested`` event, we
-        // will always be able to check if we are hovering the toasts and update
-        // expiry time before the toast actually expires.
-        let is_hovering_toasts = cursor.is_over(layout.bounds());
-        if is_hovering_toasts {
-            self.toasts.borrow_mut().iter_mut().for_each(|toast| {
-                let now = time::Instant::now();
-                let hover_timeout = time::Duration::new(2, 0);
-                toast.expiry = cmp::max(toast.expiry, now + hover_timeout)
-            })
+        // will always be able to check if we are hovering the toasts and pause
+        // their timers before the toast actually expires.
+        let now = time::Instant::now();
+        self.stack.now = Some(now);
+        let is_hovering_stack = cursor.is_over(layout.bounds());
+        if self.deck && self.stack.hovered != is_hovering_stack {
+            self.stack.hovered = is_hovering_stack;
+            shell.invalidate_layout();
+            shell.request_redraw();
+        }
+        let visible = &self.ids[..self.stack.visible.min(self.ids.len())];
+        self.toasts.borrow_mut().iter_mut().for_each(|toast| {
+            // Toasts that are still queued keep their timers frozen until
+            // they are displayed.
+            let Some(layout) = visible
+                .iter()
+                .position(|&id| id == toast.id)
+                .and_then(|index| layout.children().nth(index))
+            else {
+                toast.expiry.pause(now, time::Duration::ZERO);
+                return;
+            };
+
+            let is_dragged = self
+                .stack
+                .swipe
+                .as_ref()
+                .is_some_and(|swipe| swipe.id == toast.id);
+            let is_hovering = is_dragged
+                || match self.hover.scope {
+                    HoverScope::Stack => is_hovering_stack,
+                    HoverScope::Toast => cursor.is_over(layout.bounds()),
+                };
+            if self.hover.pause && is_hovering {
+                toast.expiry.pause(now, self.hover.grace);
+            } else if let Some(expiry) = toast.expiry.resume(now) {
+                // Nothing was waiting for the toast to expire while it was
+                // paused, so a redraw is needed to expire it on time.
+                shell.request_redraw_at(expiry);
+            }
+
+            // Running countdown bars are redrawn every frame, or only when
+            // they shrink by a tenth with reduced motion.
+            if self.countdown
+                && matches!(toast.expiry, toast::Expiry::At(_))
+                && matches!(event, Event::Window(window::Event::RedrawRequested(_)))
+            {
+                if !self.animation.reduced_motion {
+                    shell.request_redraw();
+                } else if let Some(next) = toast
+                    .timeout
+                    .and_then(|timeout| toast.expiry.next_tenth(now, timeout))
+                {
+                    shell.request_redraw_at(next);
+                }
+            }
+        });
+
+        // Toasts start animating in once they are first displayed.
+        let entered = &mut self.stack.entered;
+        entered.retain(|id, _| self.ids.contains(id));
+        for &id in visible {
+            let entered = *entered.entry(id).or_insert(now);
+            if !self.animation.is_finished(entered, now) {
+                shell.request_redraw();
+            }
+        }
+
+        // The stack is laid out again every frame while toasts collapse.
+        if visible.iter().any(|&id| self.is_exiting(id)) {
+            shell.invalidate_layout();
+            shell.request_redraw();
+        }
+
+        let exiting: Vec<ToastId> = self
+            .ids
+            .iter()
+            .copied()
+            .filter(|&id| self.is_exiting(id))
+            .collect();
+        let (ids, animation) = (self.ids, self.animation);
+        self.stack
+            .swipe
+            .take_if(|swipe| !ids.contains(&swipe.id) || exiting.contains(&swipe.id));
+        self.stack.released.retain(|id, &mut (_, released)| {
+            ids.contains(id) && (exiting.contains(id) || !animation.is_finished(released, now))
+        });
+        // Toasts which were let go of are redrawn every frame as they snap
+        // back into place.
+        if self.stack.released.keys().any(|id| !exiting.contains(id)) {
+            shell.request_redraw();
+        }
+        if self.swipe_to_dismiss {
+            self.swipe(event, cursor, now, shell);
+            if shell.is_event_captured() {
+                return;
+            }
+        }
+
+        if let Some(summary) = self.summary_layout(layout)
+            && cursor.is_over(summary.bounds())
+            && matches!(
+                event,
+                Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                    | Event::Touch(touch::Event::FingerPressed { .. })
+            )
+        {
+            if self.stack.expandable {
+                self.stack.expanded = true;
+            } else {
+                // The screen is full, so the displayed toasts make way for
+                // the next batch, and animate in again once they are back.
+                let visible = &self.ids[..self.stack.visible.min(self.ids.len())];
+                next_batch(&mut self.toasts.borrow_mut(), visible);
+                self.stack.entered.retain(|id, _| !visible.contains(id));
+                shell.invalidate_widgets();
+            }
+            shell.invalidate_layout();
+            shell.capture_event();
         }
 
         let viewport = layout.bounds();
+        let mut events = Vec::new();
+        let (is_deck, front) = (self.is_deck(), self.stack.visible.saturating_sub(1));
         self.elements
             .iter_mut()
             .zip(self.state.iter_mut())
-            .zip(layout.children())
-            .for_each(|((child, state), layout)| {
+            .zip(layout.children().take(self.stack.visible))
+            .zip(self.ids)
+            .enumerate()
+            .for_each(|(index, (((child, state), layout), &id))| {
+                // Toasts which are animating out can no longer be interacted
+                // with, and neither can the toasts hidden behind the newest
+                // toast in a deck.
+                if exiting.contains(&id) || is_deck && index != front {
+                    return;
+                }
+
+                let mut toast_shell = Shell::new(&mut events);
                 child.as_widget_mut().update(
-                    state, event, layout, cursor, renderer, clipboard, shell, &viewport,
+                    state,
+                    event,
+                    layout,
+                    cursor,
+                    renderer,
+                    clipboard,
+                    &mut toast_shell,
+                    &viewport,
                 );
+                forward(&toast_shell, shell);
+
+                events.drain(..).for_each(|event| match event {
+                    toast::Event::Dismiss => dismiss(
+                        &self.toasts,
+                        id,
+                        DismissReason::UserClosed,
+                        &self.on_dismiss,
+                        shell,
+                    ),
+                    toast::Event::Action {
+                        message,
+                        dismiss: false,
+                    }
+                    | toast::Event::Publish(message) => shell.publish(message),
+                    toast::Event::Action {
+                        message,
+                        dismiss: true,
+                    } => {
+                        shell.publish(message);
+                        dismiss(
+                            &self.toasts,
+                            id,
+                            DismissReason::Action,
+                            &self.on_dismiss,
+                            shell,
+                        );
+                    }
+                });
             });
 
-        ()
+        // A toast can only be dragged when the press was not handled by a
+        // widget inside it, such as a slider.
+        if self.swipe_to_dismiss && !shell.is_event_captured() {
+            self.press(event, layout, cursor, now);
+        }
     }
 
     fn mouse_interaction(
@@ -771,11 +2597,28 @@ impl<'a, Message> overlay::Overlay<Message, Theme, Renderer> for Overlay<'a, '_,
         cursor: mouse::Cursor,
         renderer: &Renderer,
     ) -> mouse::Interaction {
+        if let Some(summary) = self.summary_layout(layout)
+            && cursor.is_over(summary.bounds())
+        {
+            return mouse::Interaction::Pointer;
+        }
+        if self
+            .stack
+            .swipe
+            .as_ref()
+            .is_some_and(|swipe| swipe.dragging)
+        {
+            return mouse::Interaction::Grabbing;
+        }
+
         self.elements
             .iter()
             .zip(self.state.iter())
-            .zip(layout.children())
-            .map(|((child, state), layout)| {
+            .zip(layout.children().take(self.stack.visible))
+            .zip(self.ids)
+            .enumerate()
+            .filter(|(index, (_, id))| !self.is_exiting(**id) && self.is_interactive(*index))
+            .map(|(_, (((child, state), layout), _))| {
                 child.as_widget().mouse_interaction(
                     state,
                     layout,
@@ -789,10 +2632,133 @@ impl<'a, Message> overlay::Overlay<Message, Theme, Renderer> for Overlay<'a, '_,
     }
 }
 
+// Moves the displayed toasts to the back of the queue, so that the toasts
+// queued behind them are displayed next. Toasts which are animating out stay
+// where they are until they are gone.
+fn next_batch<Message>(toasts: &mut Vec<toast::Toast<'_, Message>>, visible: &[ToastId]) {
+    let (batch, rest): (Vec<_>, Vec<_>) = toasts
+        .drain(..)
+        .partition(|toast| toast.exiting.is_none() && visible.contains(&toast.id));
+    toasts.extend(rest);
+    toasts.extend(batch);
+}
+
+// The toasts of a stack which fit on-screen.
+#[derive(Debug, PartialEq)]
+struct Fit {
+    // The number of toasts displayed, oldest first.
+    visible: usize,
+    // The height taken up by the displayed toasts.
+    height: f32,
+    // Whether toasts were queued because they did not fit, rather than only
+    // because of `max_visible`.
+    is_full: bool,
+}
+
+// Takes toasts in the order they were pushed until `max_visible` is reached or
+// we run out of space, leaving room for the summary row if any toasts are
+// queued. `height_of` gives the space a toast takes up, including the spacing
+// before it. We always show at least one toast, even if it cannot fit, since it
+// would otherwise block the queue forever.
+fn fit(
+    len: usize,
+    max_visible: usize,
+    max_height: f32,
+    spacing: f32,
+    summary_height: f32,
+    mut height_of: impl FnMut(usize) -> f32,
+) -> Fit {
+    let mut heights: Vec<f32> = Vec::new();
+    let mut height = -spacing;
+    let mut is_full = false;
+    for index in 0..len.min(max_visible) {
+        let toast = height_of(index);
+        if !heights.is_empty() && height + toast > max_height {
+            is_full = true;
+            break;
+        }
+        heights.push(toast);
+        height += toast;
+    }
+
+    // Make room for the summary row by queueing more toasts if needed.
+    if heights.len() < len {
+        while heights.len() > 1 && height + spacing + summary_height > max_height {
+            height -= heights.pop().expect("stack should not be empty");
+            is_full = true;
+        }
+    }
+
+    Fit {
+        visible: heights.len(),
+        height: height.max(0.0),
+        is_full,
+    }
+}
+
+// Limits the distance a toast is dragged by to the direction away from the edge
+// of the screen it is aligned to. Centered toasts can be dragged either way.
+fn constrain_swipe(alignment: alignment::Horizontal, offset: f32) -> f32 {
+    match alignment {
+        alignment::Horizontal::Left => offset.max(0.0),
+        alignment::Horizontal::Center => offset,
+        alignment::Horizontal::Right => offset.min(0.0),
+    }
+}
+
+// Whether a toast released at `offset` is dismissed, either because it was
+// dragged far enough or because it was flicked away from its place. A toast
+// which was held still for `idle` before being released was not flicked.
+fn is_swiped(offset: f32, velocity: f32, idle: time::Duration) -> bool {
+    let velocity = if idle < SWIPE_IDLE { velocity } else { 0.0 };
+    offset.abs() >= SWIPE_DISTANCE
+        || offset != 0.0 && velocity.abs() >= SWIPE_VELOCITY && velocity.signum() == offset.signum()
+}
+
+// Starts removing a toast and lets the application know why it was dismissed.
+fn dismiss<Message>(
+    toasts: &RefCell<Vec<toast::Toast<'_, Message>>>,
+    id: ToastId,
+    reason: DismissReason,
+    on_dismiss: &OnDismiss<'_, Message>,
+    shell: &mut Shell<'_, Message>,
+) {
+    // The toast animates out before it is removed by `ToastWidget::update`.
+    let mut toasts = toasts.borrow_mut();
+    if let Some(toast) = toasts
+        .iter_mut()
+        .find(|toast| toast.id == id && toast.exiting.is_none())
+    {
+        toast.exiting = Some(time::Instant::now());
+        shell.publish(on_dismiss(id, reason));
+        shell.request_redraw();
+    }
+}
+
+// Forwards everything but the messages of a [`Shell`] used by the toast
+// elements to the [`Shell`] of the application.
+fn forward<A, B>(from: &Shell<'_, A>, to: &mut Shell<'_, B>) {
+    if from.is_event_captured() {
+        to.capture_event();
+    }
+    if from.is_layout_invalid() {
+        to.invalidate_layout();
+    }
+    if from.are_widgets_invalid() {
+        to.invalidate_widgets();
+    }
+    to.request_redraw_at(from.redraw_request());
+    to.request_input_method(from.input_method());
+}
+
 /// Defines a mapping from [`ToastLevel`] to a color that will be used to display
 /// on the border of toasts.
 pub type LevelToColorMap<'a> = Rc<dyn Fn(&ToastLevel) -> Option<Color> + 'a>;
 
+/// Defines a mapping from [`ActionKind`] to the color of the text of action
+/// buttons.
+pub type ActionToColorMap<'a> = Rc<dyn Fn(&ActionKind) -> Color + 'a>;
+
 /// Defines the styles toasts created by a `ToastContainer`
 #[derive(Clone)]
 pub struct Style<'a> {
@@ -807,6 +2773,9 @@ pub struct Style<'a> {
     /// A mapping from [`ToastLevel`] to colors, which determine color
     /// the left-border of the toast
     pub level_to_color: LevelToColorMap<'a>,
+    /// A mapping from [`ActionKind`] to colors, which determine the color of
+    /// the text of action buttons
+    pub action_to_color: ActionToColorMap<'a>,
 }
 
 impl<'a> Style<'a> {
@@ -852,6 +2821,43 @@ impl<'a> Style<'a> {
             ..self
         }
     }
+
+    /// Updates the mapping from action kinds to colors within [`Style`].
+    pub fn action_to_color(self, action_to_color: impl Fn(&ActionKind) -> Color + 'a) -> Self {
+        Self {
+            action_to_color: Rc::new(action_to_color),
+            ..self
+        }
+    }
+}
+
+impl Style<'_> {
+    // Scales the alpha channel of every color of the [`Style`] by `factor`.
+    fn scale_alpha(self, factor: f32) -> Self {
+        if factor >= 1.0 {
+            return self;
+        }
+        let level_to_color = self.level_to_color;
+        let action_to_color = self.action_to_color;
+        Style {
+            text_color: self.text_color.map(|color| color.scale_alpha(factor)),
+            background: self
+                .background
+                .map(|background| background.scale_alpha(factor)),
+            border: Border {
+                color: self.border.color.scale_alpha(factor),
+                ..self.border
+            },
+            shadow: Shadow {
+                color: self.shadow.color.scale_alpha(factor),
+                ..self.shadow
+            },
+            level_to_color: Rc::new(move |level| {
+                level_to_color(level).map(|color| color.scale_alpha(factor))
+            }),
+            action_to_color: Rc::new(move |kind| action_to_color(kind).scale_alpha(factor)),
+        }
+    }
 }
 
 impl std::fmt::Debug for Style<'_> {
@@ -865,13 +2871,37 @@ impl std::fmt::Debug for Style<'_> {
     }
 }
 
+/// The style function of a [`ToastContainer`], set with
+/// [`ToastContainer::style`].
 #[derive(Clone)]
-struct StyleFn<'a>(Rc<dyn Fn(&iced::Theme) -> Style + 'a>);
+pub struct StyleFn<'a>(Rc<dyn Fn(&iced::Theme) -> Style + 'a>);
+
+impl<'a> StyleFn<'a> {
+    /// Produces the [`Style`] of toasts for the given `theme`.
+    pub fn style<'t>(&self, theme: &'t iced::Theme) -> Style<'t> {
+        (self.0)(theme)
+    }
+
+    // Makes the colors of the style transparent by the opacity in `opacity`
+    // at the time the style is used.
+    fn fade(&self, opacity: Rc<Cell<f32>>) -> StyleFn<'a> {
+        let style_fn = self.clone();
+        StyleFn(Rc::new(move |theme| {
+            style_fn.style(theme).scale_alpha(opacity.get())
+        }))
+    }
+}
+
+impl std::fmt::Debug for StyleFn<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("StyleFn(..)")
+    }
+}
 
 impl<'a> Default for StyleFn<'a> {
     fn default() -> Self {
         StyleFn(Rc::new(|theme: &iced::Theme| {
-            let palette = theme.extended_palette().clone();
+            let palette = *theme.extended_palette();
             Style {
                 text_color: Some(palette.background.base.text),
                 background: Some(palette.background.base.color.into()),
@@ -887,6 +2917,11 @@ impl<'a> Default for StyleFn<'a> {
                     toast::Level::Warning => Some(palette.danger.strong.color),
                     toast::Level::Error => Some(palette.danger.strong.color),
                 }),
+                action_to_color: Rc::new(move |kind: &ActionKind| match kind {
+                    ActionKind::Primary => palette.primary.base.color,
+                    ActionKind::Secondary => palette.background.base.text.scale_alpha(0.7),
+                    ActionKind::Destructive => palette.danger.base.color,
+                }),
             }
         }))
     }
@@ -913,3 +2948,526 @@ pub mod style {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn container<'a>() -> ToastContainer<'a, ToastId> {
+        toast_container(|id| id)
+    }
+
+    #[test]
+    fn push_returns_a_new_id_for_each_toast() {
+        let mut toasts = container();
+        let first = toasts.push(toast("First"));
+        let second = toasts.push(toast("Second"));
+
+        assert_ne!(first, second);
+        assert_eq!(toasts.toasts.borrow().len(), 2);
+    }
+
+    #[test]
+    fn update_keeps_the_id_and_timer() {
+        let mut toasts = container();
+        let id = toasts.push(toast("Syncing..."));
+        let expiry = toast::Expiry::At(time::Instant::now());
+        toasts.toasts.borrow_mut()[0].expiry = expiry;
+
+        toasts.update(id, |toast| toast.message("Sync complete"));
+
+        let toasts = toasts.toasts.borrow();
+        assert_eq!(toasts[0].id, id);
+        assert_eq!(toasts[0].message, "Sync complete");
+        assert_eq!(toasts[0].expiry, expiry);
+    }
+
+    #[test]
+    fn update_restarts_the_timer_when_the_timeout_changes() {
+        let mut toasts = container();
+        let id = toasts.push(toast("Syncing..."));
+        toasts.toasts.borrow_mut()[0].expiry = toast::Expiry::At(time::Instant::now());
+
+        toasts.update(id, |toast| toast.timeout(time::Duration::from_secs(1)));
+
+        assert_eq!(
+            toasts.toasts.borrow()[0].expiry,
+            toast::Expiry::after(time::Duration::from_secs(1))
+        );
+    }
+
+    #[test]
+    fn update_can_replace_the_title_and_actions() {
+        let mut toasts = container();
+        let id = toasts.push(toast("Syncing...").title("Sync"));
+        let retry = |toast: Toast<'static, ToastId>| toast.clear_actions().action("Retry", id);
+
+        toasts.update(id, retry);
+        toasts.update(id, retry);
+        assert_eq!(toasts.toasts.borrow()[0].actions.len(), 1);
+
+        toasts.update(id, |toast| toast.clear_title().clear_actions());
+        let toasts = toasts.toasts.borrow();
+        assert_eq!(toasts[0].title, None);
+        assert!(toasts[0].actions.is_empty());
+    }
+
+    #[test]
+    fn duplicates_are_collapsed() {
+        let mut toasts = container().collapse_duplicates(true);
+        let first = toasts.push(toast("Failed to connect").level(ToastLevel::Error));
+        let second = toasts.push(toast("Failed to connect").level(ToastLevel::Error));
+        // A different level is not a duplicate.
+        toasts.push(toast("Failed to connect").level(ToastLevel::Warning));
+
+        assert_eq!(first, second);
+        let toasts = toasts.toasts.borrow();
+        assert_eq!(toasts.len(), 2);
+        assert_eq!(toasts[0].count, 2);
+        assert_eq!(toasts[1].count, 1);
+    }
+
+    #[test]
+    fn collapsing_restarts_the_timer() {
+        let mut toasts = container()
+            .collapse_duplicates(true)
+            .timeout(time::Duration::from_secs(5));
+        toasts.push(toast("Saved"));
+        toasts.toasts.borrow_mut()[0].expiry = toast::Expiry::At(time::Instant::now());
+
+        toasts.push(toast("Saved"));
+        assert_eq!(
+            toasts.toasts.borrow()[0].expiry,
+            toast::Expiry::after(time::Duration::from_secs(5))
+        );
+    }
+
+    #[test]
+    fn duplicates_are_kept_by_default() {
+        let mut toasts = container();
+        toasts.push(toast("Saved"));
+        toasts.push(toast("Saved"));
+
+        assert_eq!(toasts.toasts.borrow().len(), 2);
+    }
+
+    #[test]
+    fn toasts_with_a_different_timeout_are_not_collapsed() {
+        let mut toasts = container().collapse_duplicates(true);
+        toasts.push(toast("Failed to connect").sticky());
+        toasts.push(toast("Failed to connect"));
+        toasts.push(toast("Failed to connect").timeout(time::Duration::from_secs(1)));
+
+        let toasts = toasts.toasts.borrow();
+        assert_eq!(toasts.len(), 3);
+        assert_eq!(toasts[0].timeout, None);
+    }
+
+    #[test]
+    fn keyed_content_action_and_loading_toasts_are_not_collapsed() {
+        let mut toasts = container().collapse_duplicates(true);
+        toasts.push(toast("Item deleted"));
+        toasts.push(toast("Item deleted").key("autosave"));
+        toasts.push(toast("Item deleted").content(|_| iced::widget::text("Item deleted").into()));
+        toasts.push(toast("Item deleted").content(|_| iced::widget::text("Item deleted").into()));
+        toasts.push(toast("Item deleted").action("Undo", ToastId::new()));
+        toasts.push(toast("Item deleted").action("Undo", ToastId::new().next()));
+        toasts.push(toast("Item deleted").loading());
+        toasts.push(toast("Item deleted").loading());
+        toasts.push(toast("Item deleted").progress(0.5));
+
+        let toasts = toasts.toasts.borrow();
+        assert_eq!(toasts.len(), 9);
+        assert!(toasts.iter().all(|toast| toast.count == 1));
+        assert_eq!(toasts[5].actions[0].message, ToastId::new().next());
+    }
+
+    #[test]
+    fn keyed_toasts_replace_each_other() {
+        let mut toasts = container();
+        let first = toasts.push(toast("Saving...").key("autosave").loading());
+        toasts.push(toast("Unrelated"));
+        let second = toasts.push(toast("Saved").key("autosave"));
+
+        assert_eq!(first, second);
+        let toasts = toasts.toasts.borrow();
+        assert_eq!(toasts.len(), 2);
+        assert_eq!(toasts[0].id, first);
+        assert_eq!(toasts[0].message, "Saved");
+        assert!(!toasts[0].loading);
+    }
+
+    #[test]
+    fn keyed_toasts_restart_the_timer() {
+        let mut toasts = container().timeout(time::Duration::from_secs(5));
+        toasts.push(toast("Saved").key("autosave"));
+        toasts.toasts.borrow_mut()[0].expiry = toast::Expiry::At(time::Instant::now());
+
+        toasts.push(toast("Saved").key("autosave"));
+        assert_eq!(
+            toasts.toasts.borrow()[0].expiry,
+            toast::Expiry::after(time::Duration::from_secs(5))
+        );
+    }
+
+    #[test]
+    fn keyed_toasts_do_not_replace_exiting_toasts() {
+        let mut toasts = container();
+        let first = toasts.push(toast("Saved").key("autosave"));
+        toasts.dismiss(first);
+        let second = toasts.push(toast("Saved").key("autosave"));
+
+        assert_ne!(first, second);
+        assert_eq!(toasts.toasts.borrow().len(), 2);
+    }
+
+    #[test]
+    fn update_keeps_loading_until_finished() {
+        let mut toasts = container();
+        let id = toasts.push(toast("Connecting...").loading());
+
+        toasts.update(id, |toast| toast.message("Connecting (attempt 2)..."));
+        assert!(toasts.toasts.borrow()[0].loading);
+        assert_eq!(toasts.toasts.borrow()[0].expiry, toast::Expiry::Never);
+
+        toasts.update(id, |toast| toast.message("Connected").finish());
+        assert!(!toasts.toasts.borrow()[0].loading);
+        assert_eq!(
+            toasts.toasts.borrow()[0].expiry,
+            toast::Expiry::after(toasts.timeout_duration)
+        );
+    }
+
+    #[test]
+    fn set_progress_updates_the_progress_bar() {
+        let mut toasts = container();
+        let id = toasts.push(toast("Copying...").progress(0.0));
+
+        toasts.set_progress(id, 0.5);
+        assert_eq!(toasts.toasts.borrow()[0].progress, Some(0.5));
+
+        // Without a success message, a complete toast keeps its progress bar.
+        toasts.set_progress(id, 2.0);
+        assert_eq!(toasts.toasts.borrow()[0].progress, Some(1.0));
+        assert_eq!(toasts.toasts.borrow()[0].expiry, toast::Expiry::Never);
+    }
+
+    #[test]
+    fn set_progress_turns_into_a_success_toast() {
+        let mut toasts = container();
+        let id = toasts.push(
+            toast("Copying...")
+                .loading()
+                .progress(0.0)
+                .timeout(time::Duration::from_secs(1))
+                .success_on_complete("Copied"),
+        );
+
+        toasts.set_progress(id, 1.0);
+
+        let toasts = toasts.toasts.borrow();
+        assert_eq!(toasts[0].message, "Copied");
+        assert_eq!(toasts[0].level, Some(ToastLevel::Success));
+        assert_eq!(toasts[0].progress, None);
+        assert!(!toasts[0].loading);
+        assert_eq!(
+            toasts[0].expiry,
+            toast::Expiry::after(time::Duration::from_secs(1))
+        );
+    }
+
+    #[test]
+    fn set_progress_ignores_toasts_without_a_progress_bar() {
+        let mut toasts = container();
+        let id = toasts.push(toast("Saved"));
+
+        toasts.set_progress(id, 0.5);
+        let toasts = toasts.toasts.borrow();
+        assert_eq!(toasts[0].progress, None);
+        assert_ne!(toasts[0].expiry, toast::Expiry::Never);
+    }
+
+    fn save<'a>(
+        toasts: &mut ToastContainer<'a, ToastId>,
+    ) -> Task<(ToastId, Result<usize, String>)> {
+        toasts.promise(
+            Task::none(),
+            toast("Saving...").key("save"),
+            |bytes| toast(&format!("Saved {bytes} bytes")).level(ToastLevel::Success),
+            |error| toast(error).level(ToastLevel::Error),
+        )
+    }
+
+    #[test]
+    fn promises_are_resolved_with_their_result() {
+        let mut toasts = container();
+        let saved = toasts.push(toast("Saving...").key("save"));
+        let _ = save(&mut toasts);
+        let _ = save(&mut toasts);
+        let (first, second) = (saved.next(), saved.next().next());
+
+        // Promise toasts are never collapsed or replaced by key.
+        assert_eq!(toasts.toasts.borrow().len(), 3);
+        assert!(toasts.toasts.borrow()[1].loading);
+        assert_eq!(toasts.toasts.borrow()[1].expiry, toast::Expiry::Never);
+
+        toasts.resolve(first, &Ok::<usize, String>(1024));
+        toasts.resolve(second, &Err::<usize, String>("Disk full".to_string()));
+
+        let toasts = toasts.toasts.borrow();
+        assert_eq!(toasts[1].message, "Saved 1024 bytes");
+        assert_eq!(toasts[1].level, Some(ToastLevel::Success));
+        assert!(!toasts[1].loading);
+        assert_eq!(
+            toasts[1].expiry,
+            toast::Expiry::after(time::Duration::from_secs(5))
+        );
+        assert_eq!(toasts[2].message, "Disk full");
+        assert_eq!(toasts[2].level, Some(ToastLevel::Error));
+    }
+
+    #[test]
+    fn promises_are_only_resolved_once() {
+        let mut toasts = container();
+        let _ = save(&mut toasts);
+        let id = toasts.toasts.borrow()[0].id;
+
+        // A result of the wrong type is ignored.
+        toasts.resolve(id, &1024);
+        assert!(toasts.toasts.borrow()[0].loading);
+
+        toasts.resolve(id, &Ok::<usize, String>(1024));
+        toasts.update(id, |toast| toast.message("Saved"));
+        toasts.resolve(id, &Ok::<usize, String>(2048));
+
+        assert_eq!(toasts.toasts.borrow()[0].message, "Saved");
+        assert!(toasts.pending.is_empty());
+    }
+
+    #[test]
+    fn promises_of_dismissed_toasts_are_dropped() {
+        let mut toasts = container();
+        let _ = save(&mut toasts);
+        let id = toasts.toasts.borrow()[0].id;
+
+        toasts.dismiss(id);
+        toasts.resolve(id, &Ok::<usize, String>(1024));
+
+        assert_eq!(toasts.toasts.borrow()[0].message, "Saving...");
+        assert!(toasts.pending.is_empty());
+    }
+
+    fn export<'a>(toasts: &mut ToastContainer<'a, ToastId>) -> ToastId {
+        let _ = toasts.stream(stream::empty(), toast("Exporting..."), |&progress: &f32| {
+            if progress < 1.0 {
+                toast("Exporting...").progress(progress)
+            } else {
+                toast("Export complete").level(ToastLevel::Success)
+            }
+        });
+        toasts
+            .toasts
+            .borrow()
+            .last()
+            .expect("toast should be pushed")
+            .id
+    }
+
+    #[test]
+    fn streams_are_resolved_with_each_item() {
+        let mut toasts = container().collapse_duplicates(true);
+        toasts.push(toast("Exporting..."));
+        let id = export(&mut toasts);
+
+        // The stream toast is not collapsed, and does not time out while the
+        // stream runs.
+        assert_eq!(toasts.toasts.borrow().len(), 2);
+        assert!(toasts.toasts.borrow()[1].loading);
+        assert_eq!(toasts.toasts.borrow()[1].expiry, toast::Expiry::Never);
+
+        toasts.resolve(id, &Some(0.5_f32));
+        assert_eq!(toasts.toasts.borrow()[1].progress, Some(0.5));
+
+        toasts.resolve(id, &Some(1.0_f32));
+        toasts.resolve(id, &None::<f32>);
+
+        let toasts = toasts.toasts.borrow();
+        assert_eq!(toasts[1].id, id);
+        assert_eq!(toasts[1].message, "Export complete");
+        assert_eq!(
+            toasts[1].expiry,
+            toast::Expiry::after(time::Duration::from_secs(5))
+        );
+    }
+
+    #[test]
+    fn streams_are_finished_once_they_end() {
+        let mut toasts = container();
+        let id = export(&mut toasts);
+
+        toasts.resolve(id, &Some(0.5_f32));
+        toasts.resolve(id, &None::<f32>);
+        assert!(toasts.pending.is_empty());
+
+        let toasts = toasts.toasts.borrow();
+        assert_eq!(toasts[0].message, "Exporting...");
+        assert_eq!(toasts[0].progress, None);
+        assert!(!toasts[0].loading);
+        assert_ne!(toasts[0].expiry, toast::Expiry::Never);
+    }
+
+    #[test]
+    fn dismiss_is_programmatic() {
+        let mut toasts = container();
+        let id = toasts.push(toast("Uploading..."));
+
+        toasts.dismiss(id);
+        // Dismissing a toast which is already animating out does nothing.
+        toasts.dismiss(id);
+
+        assert_eq!(
+            *toasts.dismissed.borrow(),
+            [(id, DismissReason::Programmatic)]
+        );
+        assert!(toasts.toasts.borrow()[0].exiting.is_some());
+    }
+
+    #[test]
+    fn replacing_a_keyed_toast_is_a_dismissal() {
+        let mut toasts = container();
+        let id = toasts.push(toast("Saving...").key("autosave"));
+        toasts.push(toast("Saved").key("autosave"));
+
+        assert_eq!(*toasts.dismissed.borrow(), [(id, DismissReason::Replaced)]);
+        // The replacement is still on-screen.
+        assert!(toasts.toasts.borrow()[0].exiting.is_none());
+    }
+
+    #[test]
+    fn dismissing_publishes_the_reason() {
+        let toasts = toast_container(|id| (id, DismissReason::Programmatic))
+            .on_dismiss(|id, reason| (id, reason));
+        let id = ToastId::new();
+        toasts
+            .toasts
+            .borrow_mut()
+            .push(toasts.build(id, toast("Saved")));
+
+        let mut messages = Vec::new();
+        let mut shell = Shell::new(&mut messages);
+        for reason in [DismissReason::Timeout, DismissReason::UserClosed] {
+            dismiss(&toasts.toasts, id, reason, &toasts.on_dismiss, &mut shell);
+        }
+
+        // Only the first reason is published, as the toast is then exiting.
+        assert_eq!(messages, [(id, DismissReason::Timeout)]);
+    }
+
+    // Toasts 10 high with 5 between them, so `n` toasts take up `15n - 5`.
+    fn fit_toasts(len: usize, max_visible: usize, max_height: f32) -> Fit {
+        fit(len, max_visible, max_height, 5.0, 10.0, |_| 15.0)
+    }
+
+    #[test]
+    fn toasts_are_queued_by_max_visible() {
+        assert_eq!(
+            fit_toasts(5, 3, 1000.0),
+            Fit {
+                visible: 3,
+                height: 40.0,
+                is_full: false,
+            }
+        );
+    }
+
+    #[test]
+    fn toasts_are_queued_once_the_screen_is_full() {
+        // Three toasts would fit, but one more is queued to make room for the
+        // summary row.
+        assert_eq!(
+            fit_toasts(5, usize::MAX, 40.0),
+            Fit {
+                visible: 2,
+                height: 25.0,
+                is_full: true,
+            }
+        );
+        assert_eq!(
+            fit_toasts(3, usize::MAX, 40.0),
+            Fit {
+                visible: 3,
+                height: 40.0,
+                is_full: false,
+            }
+        );
+    }
+
+    #[test]
+    fn one_toast_is_displayed_even_if_it_does_not_fit() {
+        assert_eq!(
+            fit_toasts(2, usize::MAX, 5.0),
+            Fit {
+                visible: 1,
+                height: 10.0,
+                is_full: true,
+            }
+        );
+    }
+
+    #[test]
+    fn the_stack_cannot_expand_once_the_screen_is_full() {
+        // The summary row only expands the stack while toasts are queued
+        // because of `max_visible` alone.
+        assert!(!fit_toasts(5, 2, 1000.0).is_full);
+        assert!(fit_toasts(5, 3, 30.0).is_full);
+    }
+
+    #[test]
+    fn next_batch_moves_the_displayed_toasts_to_the_back() {
+        let mut toasts = container();
+        let ids: Vec<ToastId> = (0..5)
+            .map(|index| toasts.push(toast(&format!("Toast {index}"))))
+            .collect();
+        toasts.dismiss(ids[0]);
+
+        next_batch(&mut toasts.toasts.borrow_mut(), &ids[..3]);
+
+        // The exiting toast stays in front until it is gone.
+        let order: Vec<ToastId> = toasts
+            .toasts
+            .borrow()
+            .iter()
+            .map(|toast| toast.id)
+            .collect();
+        assert_eq!(order, [ids[0], ids[3], ids[4], ids[1], ids[2]]);
+    }
+
+    #[test]
+    fn toasts_are_swiped_far_enough_or_flicked() {
+        let idle = time::Duration::ZERO;
+        assert!(is_swiped(SWIPE_DISTANCE, 0.0, idle));
+        assert!(is_swiped(-SWIPE_DISTANCE, 0.0, idle));
+        assert!(!is_swiped(SWIPE_DISTANCE / 2.0, 0.0, idle));
+
+        // A flick only counts in the direction the toast was dragged in.
+        assert!(is_swiped(10.0, SWIPE_VELOCITY, idle));
+        assert!(!is_swiped(10.0, -SWIPE_VELOCITY, idle));
+        assert!(!is_swiped(0.0, SWIPE_VELOCITY, idle));
+        assert!(!is_swiped(10.0, SWIPE_VELOCITY / 2.0, idle));
+
+        // Holding the toast still before letting go cancels the flick.
+        assert!(!is_swiped(10.0, SWIPE_VELOCITY, SWIPE_IDLE));
+    }
+
+    #[test]
+    fn swipes_are_constrained_away_from_the_edge() {
+        use alignment::Horizontal::*;
+
+        assert_eq!(constrain_swipe(Left, 50.0), 50.0);
+        assert_eq!(constrain_swipe(Left, -50.0), 0.0);
+        assert_eq!(constrain_swipe(Right, 50.0), 0.0);
+        assert_eq!(constrain_swipe(Right, -50.0), -50.0);
+        assert_eq!(constrain_swipe(Center, 50.0), 50.0);
+        assert_eq!(constrain_swipe(Center, -50.0), -50.0);
+    }
+}
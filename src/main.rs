@@ -1,6 +1,6 @@
 use iced::{
     Element, Theme,
-    widget::{button, column},
+    widget::{button, column, progress_bar, row, text},
 };
 use iced_toasts::{ToastContainer, ToastId, ToastLevel, toast, toast_container};
 
@@ -26,7 +26,7 @@ enum Message {
 
 impl Default for App<'_, Message> {
     fn default() -> Self {
-        let toasts = toast_container(Message::DismissToast);
+        let toasts = toast_container(Message::DismissToast).countdown(true);
 
         Self {
             toasts,
@@ -62,7 +62,33 @@ impl App<'_, Message> {
                 self.toasts.push(
                     toast("An iced toast notification add-on")
                         .title("Iced Toasts")
-                        .level(ToastLevel::Error),
+                        .level(ToastLevel::Error)
+                        .actions([
+                            ("Retry", Message::ToastActioned(1)),
+                            ("Details", Message::ToastActioned(2)),
+                            ("Ignore", Message::ToastActioned(3)),
+                        ]),
+                );
+
+                self.toasts.push(toast("Connecting to server...").loading());
+
+                self.toasts
+                    .push(toast("Copying 12 files...").progress(0.65).percentage());
+
+                self.toasts.push(
+                    toast("Uploading \"image (4).jpeg\"")
+                        .title("Uploading")
+                        .level(ToastLevel::Info)
+                        .content(|_| {
+                            row![
+                                progress_bar(0.0..=1.0, 0.4).length(200).girth(8),
+                                text("40%"),
+                                button("Cancel").on_press(Message::ToastActioned(4)),
+                            ]
+                            .spacing(8)
+                            .align_y(iced::Alignment::Center)
+                            .into()
+                        }),
                 );
 
                 self.toasts.push(
@@ -89,8 +115,8 @@ impl App<'_, Message> {
                 );
                 self.toast_counter += 1;
             }
-            Message::DismissToast(id) => {
-                self.toasts.dismiss(id);
+            Message::DismissToast(_id) => {
+                // The toast is removed automatically once it is dismissed.
             }
             Message::ToastActioned(value) => {
                 println!("Actioned! {value}")
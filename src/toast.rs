@@ -1,17 +1,21 @@
 //! This module defines the toast element and how it should display on-screen.
 
+use std::{cell::Cell, cmp, fmt, rc::Rc};
+
 use iced::{
     Alignment, Border, Color, Element, Length, Padding, Pixels, Theme,
     border::Radius,
     time,
-    widget::{Space, button, column, container, row, scrollable, text},
+    widget::{Space, button, column, container, progress_bar, row, scrollable, text},
 };
 
 mod left_border;
+mod spinner;
 use left_border::left_border;
+use spinner::spinner;
 
 /// The type of a toast. Used to determine what color the toast should be.
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum Level {
     Info,
     Success,
@@ -29,6 +33,12 @@ impl std::fmt::Display for Level {
 #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Id(usize);
 
+impl Default for Id {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Id {
     pub fn new() -> Self {
         Id(0)
@@ -39,31 +49,274 @@ impl Id {
     }
 }
 
+/// The reason a toast was dismissed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DismissReason {
+    /// The toast ran out of time.
+    Timeout,
+    /// The user clicked the dismiss button of the toast.
+    UserClosed,
+    /// The user pressed an action button of the toast.
+    Action,
+    /// The application dismissed the toast with [`ToastContainer::dismiss`].
+    ///
+    /// [`ToastContainer::dismiss`]: crate::ToastContainer::dismiss
+    Programmatic,
+    /// The toast was replaced by pushing a new toast with the same key. The
+    /// toast keeps its id, which now refers to the new toast.
+    Replaced,
+    /// The user dragged the toast away with the mouse or a finger.
+    Swiped,
+}
+
+/// The messages produced by the widgets inside a toast. These are handled by
+/// the toast container before anything reaches the application.
+///
+/// Custom toast elements set with [`ToastContainer::view_toast`] produce these
+/// to dismiss the toast or press its actions.
+///
+/// [`ToastContainer::view_toast`]: crate::ToastContainer::view_toast
+#[derive(Clone, Debug)]
+pub enum Event<Message> {
+    /// The dismiss button was pressed.
+    Dismiss,
+    /// An action button was pressed, carrying the message of the action and
+    /// whether the toast should be dismissed.
+    Action { message: Message, dismiss: bool },
+    /// A message for the application, such as one produced by the custom
+    /// content of the toast.
+    Publish(Message),
+}
+
+/// Tracks when a toast should expire.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Expiry {
+    /// The toast never expires on its own.
+    Never,
+    /// The toast expires at the given time.
+    At(time::Instant),
+    /// The timer of the toast is paused, with `remaining` time left. Once it
+    /// is resumed, it has at least `grace` left.
+    Paused {
+        remaining: time::Duration,
+        grace: time::Duration,
+    },
+}
+
+impl Expiry {
+    /// A paused timer with `timeout` left, which starts once it is resumed.
+    pub fn after(timeout: time::Duration) -> Self {
+        Expiry::Paused {
+            remaining: timeout,
+            grace: time::Duration::ZERO,
+        }
+    }
+
+    /// Freezes the timer, leaving at least `grace` on it once it is resumed.
+    pub fn pause(&mut self, now: time::Instant, grace: time::Duration) {
+        if let Expiry::At(expiry) = *self {
+            *self = Expiry::Paused {
+                remaining: expiry.saturating_duration_since(now),
+                grace,
+            };
+        }
+    }
+
+    /// Restarts a paused timer from where it left off, and returns the time
+    /// the toast now expires at.
+    pub fn resume(&mut self, now: time::Instant) -> Option<time::Instant> {
+        let Expiry::Paused { remaining, grace } = *self else {
+            return None;
+        };
+        let expiry = now + cmp::max(remaining, grace);
+        *self = Expiry::At(expiry);
+        Some(expiry)
+    }
+
+    /// Returns the next time a running timer of `timeout` crosses a tenth of
+    /// `timeout`, which is when a countdown bar shrinking in tenths changes.
+    pub fn next_tenth(&self, now: time::Instant, timeout: time::Duration) -> Option<time::Instant> {
+        let Expiry::At(expiry) = *self else {
+            return None;
+        };
+        let tenth = timeout / 10;
+        if tenth.is_zero() || expiry <= now {
+            return None;
+        }
+        let tenths = expiry
+            .saturating_duration_since(now)
+            .as_nanos()
+            .div_ceil(tenth.as_nanos());
+        Some(expiry - tenth * (tenths - 1) as u32)
+    }
+}
+
+/// The kind of an action button. Used to determine how the button should look.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ActionKind {
+    /// The main action of the toast.
+    #[default]
+    Primary,
+    /// A less important action, displayed as subdued text.
+    Secondary,
+    /// An action which destroys something, such as "Delete permanently".
+    Destructive,
+}
+
+/// An action button displayed on a toast, which broadcasts `message` when
+/// pressed.
+#[derive(Clone, Debug)]
+pub struct Action<Message> {
+    /// The text displayed on the button.
+    pub text: String,
+    /// The message broadcast when the button is pressed.
+    pub message: Message,
+    /// The kind of the action.
+    pub kind: ActionKind,
+    /// Whether pressing the button also dismisses the toast.
+    pub dismiss: bool,
+}
+
+impl<Message> Action<Message> {
+    /// Creates a new [`Action`]. `text` is displayed on the button, and
+    /// `message` is broadcast when the button is pressed.
+    pub fn new(text: &str, message: Message) -> Self {
+        Action {
+            text: text.to_string(),
+            message,
+            kind: ActionKind::default(),
+            dismiss: true,
+        }
+    }
+
+    /// Sets the kind of the [`Action`]. Default is [`ActionKind::Primary`].
+    pub fn kind(mut self, kind: ActionKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    /// Sets whether pressing the [`Action`] also dismisses the toast, with a
+    /// reason of [`DismissReason::Action`]. Default is `true`.
+    pub fn dismiss(mut self, dismiss: bool) -> Self {
+        self.dismiss = dismiss;
+        self
+    }
+}
+
+impl<Message> From<(&str, Message)> for Action<Message> {
+    fn from((text, message): (&str, Message)) -> Self {
+        Action::new(text, message)
+    }
+}
+
+/// Custom content displayed in a toast in place of its message.
+pub struct Content<'a, Message>(pub Rc<dyn Fn(Id) -> Element<'a, Message> + 'a>);
+
+impl<Message> Clone for Content<'_, Message> {
+    fn clone(&self) -> Self {
+        Content(self.0.clone())
+    }
+}
+
+impl<Message> fmt::Debug for Content<'_, Message> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Content(..)")
+    }
+}
+
 /// A toast notification to be displayed on screen. Can be created with `toast()`
+/// and pushed to a [`ToastContainer`], which stores it as this type.
+///
+/// [`ToastContainer`]: crate::ToastContainer
 #[derive(Clone, Debug)]
-pub struct Toast<Message> {
+pub struct Toast<'a, Message> {
+    /// The id of the toast.
     pub id: Id,
-    pub expiry: time::Instant,
+    pub(crate) expiry: Expiry,
+    /// The amount of time the toast is displayed for, or `None` if it never
+    /// expires. Loading and progress toasts only start timing out once they
+    /// have finished.
+    pub(crate) timeout: Option<time::Duration>,
 
+    /// The level of the toast.
     pub level: Option<Level>,
+    /// The title of the toast.
     pub title: Option<String>,
+    /// The message of the toast.
     pub message: String,
 
-    // TODO: Support having multiple action buttons
-    pub action: Option<(String, Message)>,
-    pub on_dismiss: Message,
+    /// The action buttons of the toast.
+    pub actions: Vec<Action<Message>>,
+
+    /// A hash of the key of the toast, used to replace toasts with the same
+    /// key.
+    pub(crate) key: Option<u64>,
+    /// Custom content displayed instead of the message.
+    pub(crate) content: Option<Content<'a, Message>>,
+    /// Whether the toast displays a spinner, as the task it describes is still
+    /// in progress.
+    pub loading: bool,
+    /// The value of the progress bar of the toast, from 0.0 to 1.0, or `None`
+    /// if it has no progress bar.
+    pub progress: Option<f32>,
+    /// Whether the progress is also displayed as a percentage.
+    pub(crate) percentage: bool,
+    /// The message of the success toast the toast turns into once its progress
+    /// is complete.
+    pub(crate) success_message: Option<String>,
+    /// The time the toast was dismissed at, if it is animating out before it
+    /// is removed.
+    pub(crate) exiting: Option<time::Instant>,
+    /// The opacity of the toast while it animates in and out, which is applied
+    /// to its style when it is drawn.
+    pub(crate) opacity: Rc<Cell<f32>>,
+    /// The number of identical toasts that have been collapsed into this one.
+    pub count: usize,
+}
+
+impl<Message> Toast<'_, Message> {
+    /// Whether the toast is loading or has a progress bar, and so does not
+    /// time out yet.
+    pub(crate) fn is_in_progress(&self) -> bool {
+        self.loading || self.progress.is_some()
+    }
+
+    /// The fraction of the lifetime of the toast that is left at `now`, from
+    /// 0.0 to 1.0, or `None` if it never expires.
+    pub(crate) fn remaining(&self, now: time::Instant) -> Option<f32> {
+        let timeout = self.timeout?;
+        let remaining = match self.expiry {
+            Expiry::Never => return None,
+            Expiry::At(expiry) => expiry.saturating_duration_since(now),
+            Expiry::Paused { remaining, .. } => remaining,
+        };
+        Some((remaining.as_secs_f32() / timeout.as_secs_f32()).min(1.0))
+    }
 }
 
-impl<'a, Message> Toast<Message>
+impl<'a, Message> Toast<'a, Message>
 where
     Message: 'a + Clone,
 {
-    pub fn view(&self, text_size: Pixels, style_fn: super::StyleFn<'a>) -> Element<'a, Message> {
+    /// Produces the custom content of the toast set with `Toast::content`, if
+    /// it has any.
+    pub fn content(&self) -> Option<Element<'a, Event<Message>>> {
+        self.content
+            .as_ref()
+            .map(|content| (content.0)(self.id).map(Event::Publish))
+    }
+
+    pub(crate) fn view(
+        &self,
+        text_size: Pixels,
+        reduced_motion: bool,
+        style_fn: super::StyleFn<'a>,
+    ) -> Element<'a, Event<Message>> {
         let toast = self.clone();
 
-        let content: Element<Message> = {
+        let content: Element<Event<Message>> = {
             let style_fn_title = style_fn.clone().0;
-            let title: Element<Message> = toast
+            let title: Element<Event<Message>> = toast
                 .title
                 .map(|title| {
                     text(title)
@@ -82,68 +335,169 @@ where
                 })
                 .unwrap_or(Space::new().into());
 
-            let style_fn_message = style_fn.clone().0;
-            let message = text(toast.message)
-                .style(move |theme| {
-                    let toast_style = style_fn_message(theme);
-                    text::Style {
-                        color: toast_style.text_color,
-                    }
-                })
-                .size(text_size);
-
-            container(scrollable(
-                column![title, message].padding(Padding::default().right(10)),
-            ))
-            .max_width(500)
-            .height(Length::Shrink)
-            .padding(Padding {
-                top: 10.0,
-                right: 10.0,
-                bottom: 10.0,
-                left: 20.0,
-            })
-            .into()
-        };
-
-        let action_button: Element<'a, Message> = toast
-            .action
-            .map(|(button_str, message)| {
-                container(
-                    button(text(button_str).size(text_size))
-                        .style(|theme: &Theme, status| {
-                            let palette = theme.extended_palette();
+            // Colors which do not come from `style_fn` are faded by hand as
+            // the toast animates in and out.
+            let badge: Element<Event<Message>> = if toast.count > 1 {
+                let opacity = toast.opacity.clone();
+                container(text(format!("×{}", toast.count)).size(text_size.0 * 0.75))
+                    .padding([0, 6])
+                    .style(move |theme: &Theme| {
+                        let palette = theme.extended_palette();
+                        let opacity = opacity.get();
+                        container::Style {
+                            text_color: Some(palette.background.strong.text.scale_alpha(opacity)),
+                            background: Some(
+                                palette.background.strong.color.scale_alpha(opacity).into(),
+                            ),
+                            border: Border {
+                                radius: 8.0.into(),
+                                ..Border::default()
+                            },
+                            ..container::Style::default()
+                        }
+                    })
+                    .into()
+            } else {
+                Space::new().into()
+            };
+            let header = row![title, badge].spacing(6).align_y(Alignment::Center);
 
-                            let background = match status {
-                                button::Status::Active => None,
-                                button::Status::Hovered => Some(palette.background.weak.color),
-                                button::Status::Pressed => Some(palette.background.strong.color),
-                                button::Status::Disabled => None,
+            let message: Element<Event<Message>> = match self.content() {
+                Some(content) => content,
+                None => {
+                    let style_fn_message = style_fn.clone().0;
+                    text(toast.message.clone())
+                        .style(move |theme| {
+                            let toast_style = style_fn_message(theme);
+                            text::Style {
+                                color: toast_style.text_color,
                             }
-                            .map(iced::Background::Color);
-
-                            button::Style {
-                                background,
-                                text_color: palette.primary.base.color,
-                                border: iced::Border {
-                                    color: Color::WHITE,
-                                    width: 0.0,
-                                    radius: 5.0.into(),
+                        })
+                        .size(text_size)
+                        .into()
+                }
+            };
+
+            let progress: Element<Event<Message>> = match toast.progress {
+                Some(progress) => {
+                    let style_fn_progress = style_fn.clone().0;
+                    let level = toast.level;
+                    let opacity = toast.opacity.clone();
+                    let bar = progress_bar(0.0..=1.0, progress)
+                        .girth(6)
+                        .style(move |theme| {
+                            let toast_style = style_fn_progress(theme);
+                            let palette = theme.extended_palette();
+                            progress_bar::Style {
+                                background: palette
+                                    .background
+                                    .strong
+                                    .color
+                                    .scale_alpha(opacity.get())
+                                    .into(),
+                                bar: level
+                                    .and_then(|level| (toast_style.level_to_color)(&level))
+                                    .unwrap_or(
+                                        palette.primary.base.color.scale_alpha(opacity.get()),
+                                    )
+                                    .into(),
+                                border: Border {
+                                    radius: 3.0.into(),
+                                    ..Border::default()
                                 },
-                                ..button::Style::default()
                             }
-                        })
-                        .on_press(message),
-                )
+                        });
+                    let percentage: Element<Event<Message>> = if toast.percentage {
+                        text(format!("{:.0}%", progress * 100.0))
+                            .size(text_size.0 * 0.75)
+                            .into()
+                    } else {
+                        Space::new().into()
+                    };
+                    row![bar, percentage]
+                        .spacing(8)
+                        .padding(Padding::default().top(6))
+                        .align_y(Alignment::Center)
+                        .into()
+                }
+                None => Space::new().into(),
+            };
+
+            let body = scrollable(
+                column![header, message, progress].padding(Padding::default().right(10)),
+            );
+
+            // A loading toast displays a spinner beside the message, in place
+            // of the level accent.
+            let body: Element<Event<Message>> = if toast.loading {
+                let style_fn_spinner = style_fn.clone().0;
+                let level = toast.level;
+                let opacity = toast.opacity.clone();
+                let spinner = spinner()
+                    .reduced_motion(reduced_motion)
+                    .style(move |theme| {
+                        let toast_style = style_fn_spinner(theme);
+                        level
+                            .and_then(|level| (toast_style.level_to_color)(&level))
+                            .or(toast_style.text_color)
+                            .unwrap_or(
+                                theme
+                                    .extended_palette()
+                                    .background
+                                    .base
+                                    .text
+                                    .scale_alpha(opacity.get()),
+                            )
+                    });
+                row![spinner, body]
+                    .spacing(10)
+                    .align_y(Alignment::Center)
+                    .into()
+            } else {
+                body.into()
+            };
+
+            // Multiple actions are displayed in a row underneath the message,
+            // wrapping onto more rows if they do not fit.
+            let body: Element<Event<Message>> = if toast.actions.len() > 1 {
+                let actions = toast
+                    .actions
+                    .iter()
+                    .cloned()
+                    .map(|action| action_button(action, text_size, style_fn.clone()));
+                column![body, row(actions).spacing(4).wrap().vertical_spacing(4)]
+                    .spacing(6)
+                    .into()
+            } else {
+                body
+            };
+
+            container(body)
+                .max_width(500)
+                .height(Length::Shrink)
+                .padding(Padding {
+                    top: 10.0,
+                    right: 10.0,
+                    bottom: 10.0,
+                    left: 20.0,
+                })
+                .into()
+        };
+
+        // A single action is displayed beside the message instead.
+        let action_button: Element<'a, Event<Message>> = match toast.actions.as_slice() {
+            [action] => container(action_button(action.clone(), text_size, style_fn.clone()))
                 .align_y(Alignment::Center)
                 .height(Length::Fill)
-                .into()
-            })
-            .unwrap_or_else(|| Space::new().into());
+                .into(),
+            _ => Space::new().into(),
+        };
 
-        let dismiss_button: Element<Message> = container(
+        let style_fn_dismiss = style_fn.clone().0;
+        let dismiss_button: Element<Event<Message>> = container(
             button(text("×").size(28))
-                .style(|theme: &Theme, status| {
+                .style(move |theme: &Theme, status| {
+                    let toast_style = style_fn_dismiss(theme);
                     let palette = theme.extended_palette();
 
                     let background = match status {
@@ -156,7 +510,9 @@ where
 
                     button::Style {
                         background,
-                        text_color: palette.background.base.text,
+                        text_color: toast_style
+                            .text_color
+                            .unwrap_or(palette.background.base.text),
                         border: iced::Border {
                             color: Color::TRANSPARENT,
                             width: 0.0,
@@ -166,7 +522,7 @@ where
                     }
                 })
                 .width(40)
-                .on_press(toast.on_dismiss),
+                .on_press(Event::Dismiss),
         )
         .center_y(Length::Fill)
         .height(Length::Fixed(55.0))
@@ -176,7 +532,7 @@ where
 
         let style_fn_left_border = style_fn.clone().0;
         let style_fn_container = style_fn.clone().0;
-        let toast_element: Element<Message> = container(
+        let toast_element: Element<Event<Message>> = container(
             left_border(
                 row![content, action_button, dismiss_button, right_padding]
                     .height(Length::Shrink)
@@ -187,6 +543,7 @@ where
 
                 let color = toast
                     .level
+                    .filter(|_| !toast.loading)
                     .map(|level| (toast_style.level_to_color)(&level).unwrap_or(Color::TRANSPARENT))
                     .unwrap_or(Color::TRANSPARENT);
 
@@ -219,3 +576,123 @@ where
         toast_element
     }
 }
+
+fn action_button<'a, Message: 'a + Clone>(
+    action: Action<Message>,
+    text_size: Pixels,
+    style_fn: super::StyleFn<'a>,
+) -> Element<'a, Event<Message>> {
+    button(text(action.text).size(text_size))
+        .style(move |theme: &Theme, status| {
+            let toast_style = style_fn.0(theme);
+            let palette = theme.extended_palette();
+
+            let background = match status {
+                button::Status::Active => None,
+                button::Status::Hovered => Some(palette.background.weak.color),
+                button::Status::Pressed => Some(palette.background.strong.color),
+                button::Status::Disabled => None,
+            }
+            .map(iced::Background::Color);
+
+            button::Style {
+                background,
+                text_color: (toast_style.action_to_color)(&action.kind),
+                border: iced::Border {
+                    color: Color::WHITE,
+                    width: 0.0,
+                    radius: 5.0.into(),
+                },
+                ..button::Style::default()
+            }
+        })
+        .on_press(Event::Action {
+            message: action.message,
+            dismiss: action.dismiss,
+        })
+        .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SECOND: time::Duration = time::Duration::from_secs(1);
+
+    #[test]
+    fn pause_freezes_remaining_time() {
+        let now = time::Instant::now();
+        let mut expiry = Expiry::At(now + 5 * SECOND);
+
+        expiry.pause(now + 2 * SECOND, time::Duration::ZERO);
+        assert_eq!(expiry, Expiry::after(3 * SECOND));
+
+        // Pausing again does not change the time left.
+        expiry.pause(now + 4 * SECOND, time::Duration::ZERO);
+        assert_eq!(expiry, Expiry::after(3 * SECOND));
+    }
+
+    #[test]
+    fn grace_is_applied_on_resume() {
+        let now = time::Instant::now();
+        let mut expiry = Expiry::At(now + SECOND);
+
+        // The time left stays frozen while paused, even if it is less than
+        // the grace.
+        expiry.pause(now, 2 * SECOND);
+        assert_eq!(
+            expiry,
+            Expiry::Paused {
+                remaining: SECOND,
+                grace: 2 * SECOND
+            }
+        );
+
+        assert_eq!(expiry.resume(now), Some(now + 2 * SECOND));
+    }
+
+    #[test]
+    fn resume_restarts_from_where_it_left_off() {
+        let now = time::Instant::now();
+        let mut expiry = Expiry::after(3 * SECOND);
+
+        assert_eq!(expiry.resume(now), Some(now + 3 * SECOND));
+        assert_eq!(expiry, Expiry::At(now + 3 * SECOND));
+
+        // Resuming a running timer does nothing.
+        assert_eq!(expiry.resume(now + SECOND), None);
+        assert_eq!(expiry, Expiry::At(now + 3 * SECOND));
+    }
+
+    #[test]
+    fn next_tenth_is_the_next_step_of_the_countdown() {
+        let now = time::Instant::now();
+        let timeout = 10 * SECOND;
+        let expiry = Expiry::At(now + timeout);
+
+        assert_eq!(expiry.next_tenth(now, timeout), Some(now + SECOND));
+        assert_eq!(
+            expiry.next_tenth(now + SECOND / 2, timeout),
+            Some(now + SECOND)
+        );
+        assert_eq!(
+            expiry.next_tenth(now + 9 * SECOND + SECOND / 2, timeout),
+            Some(now + 10 * SECOND)
+        );
+
+        // Expired and paused timers have no steps left.
+        assert_eq!(expiry.next_tenth(now + timeout, timeout), None);
+        assert_eq!(Expiry::after(timeout).next_tenth(now, timeout), None);
+    }
+
+    #[test]
+    fn never_is_unaffected() {
+        let now = time::Instant::now();
+        let mut expiry = Expiry::Never;
+
+        expiry.pause(now, SECOND);
+        assert_eq!(expiry, Expiry::Never);
+        assert_eq!(expiry.resume(now), None);
+        assert_eq!(expiry, Expiry::Never);
+    }
+}
@@ -0,0 +1,187 @@
+//! This module defines a custom [`Spinner`] widget which displays a ring of dots
+//! that animates while something is loading.
+
+use std::{f32::consts::PI, rc::Rc};
+
+use iced::{
+    Border, Color, Element, Event, Length, Point, Rectangle, Renderer, Shadow, Size, Theme,
+    advanced::{
+        Clipboard, Layout, Shell, Widget, layout, renderer,
+        widget::{Tree, tree},
+    },
+    mouse, time, window,
+};
+
+// The number of dots in the ring.
+const DOTS: usize = 8;
+// The amount of time it takes for the ring to complete a full rotation.
+const PERIOD: time::Duration = time::Duration::from_millis(960);
+
+pub struct Spinner<'a> {
+    size: f32,
+    reduced_motion: bool,
+    style_fn: StyleFn<'a>,
+}
+
+pub fn spinner<'a>() -> Spinner<'a> {
+    Spinner::new()
+}
+
+impl<'a> Spinner<'a> {
+    fn new() -> Self {
+        Spinner {
+            size: 20.0,
+            reduced_motion: false,
+            style_fn: StyleFn::default(),
+        }
+    }
+
+    pub fn size(mut self, size: f32) -> Self {
+        self.size = size;
+        self
+    }
+
+    // A spinner with reduced motion stands still, showing its first frame.
+    pub fn reduced_motion(mut self, reduced_motion: bool) -> Self {
+        self.reduced_motion = reduced_motion;
+        self
+    }
+
+    pub fn style(mut self, style_fn: impl Fn(&Theme) -> Color + 'a) -> Self {
+        self.style_fn = StyleFn(Rc::new(style_fn));
+        self
+    }
+}
+
+impl<'a, Message> From<Spinner<'a>> for Element<'a, Message>
+where
+    Message: 'a,
+{
+    fn from(spinner: Spinner<'a>) -> Element<'a, Message> {
+        Element::new(spinner)
+    }
+}
+
+// The time at which the spinner started animating, and the time of the latest
+// frame.
+#[derive(Default)]
+struct State {
+    start: Option<time::Instant>,
+    elapsed: time::Duration,
+}
+
+impl<Message> Widget<Message, Theme, Renderer> for Spinner<'_> {
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: Length::Fixed(self.size),
+            height: Length::Fixed(self.size),
+        }
+    }
+
+    fn layout(
+        &mut self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::atomic(limits, self.size, self.size)
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        _layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        if let Event::Window(window::Event::RedrawRequested(now)) = event
+            && !self.reduced_motion
+        {
+            let state = tree.state.downcast_mut::<State>();
+            let start = *state.start.get_or_insert(*now);
+            state.elapsed = now.saturating_duration_since(start);
+
+            // The ring only changes once the brightest dot moves on to the
+            // next dot.
+            let step = PERIOD / DOTS as u32;
+            let steps = (state.elapsed.as_nanos() / step.as_nanos()) as u32;
+            shell.request_redraw_at(start + step * (steps + 1));
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _renderer_style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        use iced::advanced::Renderer as _;
+
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let color = self.style_fn.0(theme);
+
+        // The brightest dot moves around the ring, with the dots behind it
+        // fading out.
+        let turns = state.elapsed.as_secs_f32() / PERIOD.as_secs_f32();
+        let head = (turns.fract() * DOTS as f32) as usize;
+
+        let dot_radius = self.size / 10.0;
+        let ring_radius = self.size / 2.0 - dot_radius;
+        let center = bounds.center();
+
+        for dot in 0..DOTS {
+            let angle = dot as f32 * 2.0 * PI / DOTS as f32 - PI / 2.0;
+            let position = Point::new(
+                center.x + ring_radius * angle.cos(),
+                center.y + ring_radius * angle.sin(),
+            );
+            let distance = (head + DOTS - dot) % DOTS;
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: position.x - dot_radius,
+                        y: position.y - dot_radius,
+                        width: dot_radius * 2.0,
+                        height: dot_radius * 2.0,
+                    },
+                    border: Border {
+                        radius: dot_radius.into(),
+                        ..Border::default()
+                    },
+                    shadow: Shadow::default(),
+                    snap: false,
+                },
+                color.scale_alpha(1.0 - distance as f32 / DOTS as f32),
+            );
+        }
+    }
+}
+
+#[derive(Clone)]
+struct StyleFn<'a>(Rc<dyn Fn(&iced::Theme) -> Color + 'a>);
+
+impl<'a> Default for StyleFn<'a> {
+    fn default() -> Self {
+        StyleFn(Rc::new(|theme: &iced::Theme| {
+            theme.extended_palette().background.base.text
+        }))
+    }
+}